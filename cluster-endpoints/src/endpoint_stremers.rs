@@ -1,7 +1,14 @@
-use solana_lite_rpc_core::types::{BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream};
+use solana_lite_rpc_core::types::{
+    AccountFiltersSender, AccountStream, BlockStream, ClusterInfoStream, SlotStream,
+    VoteAccountStream,
+};
 pub struct EndpointStreaming {
     pub blocks_notifier: BlockStream,
     pub slot_notifier: SlotStream,
     pub vote_account_notifier: VoteAccountStream,
     pub cluster_info_notifier: ClusterInfoStream,
+    /// account updates, only streamed by geyser
+    pub account_notifier: Option<AccountStream>,
+    /// accounts and programs streamed by `account_notifier`
+    pub account_filters_sender: Option<AccountFiltersSender>,
 }
@@ -0,0 +1,270 @@
+use anyhow::Context;
+use futures::{SinkExt, StreamExt};
+use geyser_grpc_connector::grpc_subscription_autoreconnect::GrpcSourceConfig;
+use solana_lite_rpc_core::{
+    commitment_utils::Commitment,
+    structures::account_data::{AccountData, AccountNotificationMessage, AccountStreamFilter},
+    types::{AccountFiltersReceiver, AccountFiltersSender, AccountStream},
+    AnyhowJoinHandle,
+};
+use solana_rpc_client_api::filter::RpcFilterType;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::broadcast::Sender;
+use yellowstone_grpc_client::GeyserGrpcClient;
+use yellowstone_grpc_proto::geyser::{
+    subscribe_request_filter_accounts_filter::Filter as AccountsFilterOneof,
+    subscribe_request_filter_accounts_filter_memcmp::Data as MemcmpDataOneof,
+    subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest,
+    SubscribeRequestFilterAccounts, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterAccountsFilterMemcmp, SubscribeUpdateAccount,
+};
+
+// delays before subscribing again after a failure, doubled after each consecutive failure
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Stream the updates of the accounts and programs set with the returned filters sender.
+///
+/// A geyser subscription is made for each commitment having filters, the filters are updated on
+/// the subscription when they change and the subscription is made again with a backoff when
+/// it fails. Accounts are streamed from the first grpc source only.
+pub fn create_grpc_account_streaming(
+    grpc_sources: Vec<GrpcSourceConfig>,
+) -> (AccountStream, AccountFiltersSender, Vec<AnyhowJoinHandle>) {
+    let (account_sender, account_stream) = tokio::sync::broadcast::channel(10000);
+    let (filters_sender, filters_receiver) = tokio::sync::watch::channel(HashMap::new());
+
+    let Some(grpc_source) = grpc_sources.into_iter().next() else {
+        log::info!("No grpc source configured, accounts are not streamed");
+        return (account_stream, filters_sender, vec![]);
+    };
+
+    let tasks = [
+        Commitment::Processed,
+        Commitment::Confirmed,
+        Commitment::Finalized,
+    ]
+    .into_iter()
+    .map(|commitment| {
+        create_account_streaming_task(
+            grpc_source.clone(),
+            commitment,
+            filters_receiver.clone(),
+            account_sender.clone(),
+        )
+    })
+    .collect();
+    (account_stream, filters_sender, tasks)
+}
+
+fn create_account_streaming_task(
+    grpc_source: GrpcSourceConfig,
+    commitment: Commitment,
+    mut filters: AccountFiltersReceiver,
+    account_sender: Sender<AccountNotificationMessage>,
+) -> AnyhowJoinHandle {
+    let commitment_level = match commitment {
+        Commitment::Processed => CommitmentLevel::Processed,
+        Commitment::Confirmed => CommitmentLevel::Confirmed,
+        Commitment::Finalized => CommitmentLevel::Finalized,
+    };
+    tokio::spawn(async move {
+        let mut retry_after = MIN_RETRY_DELAY;
+        loop {
+            let mut filter = filters
+                .borrow_and_update()
+                .get(&commitment)
+                .cloned()
+                .unwrap_or_default();
+            if filter.is_empty() {
+                // nothing to stream until an account is subscribed at this commitment
+                filters
+                    .changed()
+                    .await
+                    .context("Account filters sender dropped")?;
+                continue;
+            }
+
+            let mut client = match GeyserGrpcClient::connect(
+                grpc_source.grpc_addr.clone(),
+                grpc_source.grpc_x_token.clone(),
+                None,
+            ) {
+                Ok(client) => client,
+                Err(err) => {
+                    log::error!("Grpc account connection at {commitment:?} failed (retrying in {retry_after:?}): {err}");
+                    wait_before_retry(&mut retry_after).await;
+                    continue;
+                }
+            };
+            let (mut subscribe_sender, mut stream) = match client.subscribe().await {
+                Ok(subscription) => subscription,
+                Err(err) => {
+                    log::error!("Grpc account subscription at {commitment:?} failed (retrying in {retry_after:?}): {err}");
+                    wait_before_retry(&mut retry_after).await;
+                    continue;
+                }
+            };
+            if let Err(err) = subscribe_sender
+                .send(subscribe_request(&filter, commitment_level))
+                .await
+            {
+                log::error!("Grpc account subscription at {commitment:?} failed (retrying in {retry_after:?}): {err}");
+                wait_before_retry(&mut retry_after).await;
+                continue;
+            }
+
+            loop {
+                tokio::select! {
+                    message = stream.next() => {
+                        let Some(message) = message else {
+                            log::error!("Grpc account subscription at {commitment:?} broken (resubscribing)");
+                            wait_before_retry(&mut retry_after).await;
+                            break;
+                        };
+                        let update = match message {
+                            Ok(message) => message.update_oneof,
+                            Err(status) => {
+                                log::error!("Grpc account subscription at {commitment:?} error {status} (resubscribing)");
+                                wait_before_retry(&mut retry_after).await;
+                                break;
+                            }
+                        };
+                        retry_after = MIN_RETRY_DELAY;
+                        match update {
+                            Some(UpdateOneof::Account(account)) => {
+                                if let Some(data) = map_account_update(account) {
+                                    // there is no receiver when nobody subscribes to accounts
+                                    let _ = account_sender.send(AccountNotificationMessage { data, commitment });
+                                }
+                            }
+                            Some(UpdateOneof::Ping(_)) => log::trace!("GRPC Ping"),
+                            _ => log::trace!("unknown GRPC notification"),
+                        }
+                    }
+                    res = filters.changed() => {
+                        res.context("Account filters sender dropped")?;
+                        let new_filter = filters
+                            .borrow_and_update()
+                            .get(&commitment)
+                            .cloned()
+                            .unwrap_or_default();
+                        if new_filter == filter {
+                            continue;
+                        }
+                        // the filters of the subscription are replaced by the ones of the last request
+                        if let Err(err) = subscribe_sender
+                            .send(subscribe_request(&new_filter, commitment_level))
+                            .await
+                        {
+                            log::error!("Grpc account filters update at {commitment:?} failed (resubscribing): {err}");
+                            break;
+                        }
+                        filter = new_filter;
+                    }
+                }
+            }
+        }
+    })
+}
+
+async fn wait_before_retry(retry_after: &mut Duration) {
+    tokio::time::sleep(*retry_after).await;
+    *retry_after = (*retry_after * 2).min(MAX_RETRY_DELAY);
+}
+
+fn subscribe_request(
+    filter: &AccountStreamFilter,
+    commitment: CommitmentLevel,
+) -> SubscribeRequest {
+    SubscribeRequest {
+        slots: HashMap::new(),
+        accounts: geyser_accounts_filter(filter),
+        transactions: HashMap::new(),
+        entry: HashMap::new(),
+        blocks: HashMap::new(),
+        blocks_meta: HashMap::new(),
+        commitment: Some(commitment as i32),
+        accounts_data_slice: vec![],
+        ping: None,
+    }
+}
+
+fn geyser_accounts_filter(
+    filter: &AccountStreamFilter,
+) -> HashMap<String, SubscribeRequestFilterAccounts> {
+    let mut accounts = HashMap::new();
+    if !filter.accounts.is_empty() {
+        accounts.insert(
+            "accounts".to_string(),
+            SubscribeRequestFilterAccounts {
+                account: filter.accounts.iter().map(Pubkey::to_string).collect(),
+                owner: vec![],
+                filters: vec![],
+            },
+        );
+    }
+    // programs having the same filters share a geyser filter
+    let mut programs_by_filters: HashMap<&Vec<RpcFilterType>, Vec<String>> = HashMap::new();
+    for (program_id, filters) in &filter.programs {
+        programs_by_filters
+            .entry(filters)
+            .or_default()
+            .push(program_id.to_string());
+    }
+    for (index, (filters, owner)) in programs_by_filters.into_iter().enumerate() {
+        accounts.insert(
+            format!("programs_{index}"),
+            SubscribeRequestFilterAccounts {
+                account: vec![],
+                owner,
+                filters: filters.iter().filter_map(geyser_account_filter).collect(),
+            },
+        );
+    }
+    accounts
+}
+
+// the token account state filter is only applied by lite-rpc
+fn geyser_account_filter(filter: &RpcFilterType) -> Option<SubscribeRequestFilterAccountsFilter> {
+    let filter = match filter {
+        RpcFilterType::DataSize(size) => AccountsFilterOneof::Datasize(*size),
+        RpcFilterType::Memcmp(memcmp) => {
+            AccountsFilterOneof::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                offset: memcmp.offset as u64,
+                data: Some(MemcmpDataOneof::Bytes(memcmp.bytes()?.into_owned())),
+            })
+        }
+        RpcFilterType::TokenAccountState => return None,
+    };
+    Some(SubscribeRequestFilterAccountsFilter {
+        filter: Some(filter),
+    })
+}
+
+pub fn map_account_update(update: SubscribeUpdateAccount) -> Option<AccountData> {
+    let Some(account) = update.account else {
+        log::warn!("Receive a SubscribeUpdateAccount without account.");
+        return None;
+    };
+    let (Ok(pubkey), Ok(owner)) = (
+        Pubkey::try_from(account.pubkey),
+        Pubkey::try_from(account.owner),
+    ) else {
+        log::warn!("Receive a SubscribeUpdateAccount with an invalid pubkey.");
+        return None;
+    };
+    Some(AccountData {
+        pubkey,
+        account: Arc::new(Account {
+            lamports: account.lamports,
+            data: account.data,
+            owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        }),
+        updated_slot: update.slot,
+        write_version: account.write_version,
+    })
+}
@@ -1,3 +1,4 @@
+use crate::json_rpc_leaders_getter::JsonRpcLeaderGetter;
 use anyhow::{bail, Error};
 use async_trait::async_trait;
 use solana_lite_rpc_core::structures::epoch::EpochCache;
@@ -8,25 +9,29 @@ use solana_lite_rpc_core::{
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-// Stores leaders for slots from older to newer in leader schedule
-// regularly removed old leaders and adds new ones
+// Leaders from the schedule calculated with the stakes and votes notified by geyser,
+// the rpc is used while this schedule does not cover the requested slots
+// (during bootstrap or at the epoch change before the next schedule is calculated)
 pub struct GrpcLeaderGetter {
     epoch_data: EpochCache,
     leader_schedule: Arc<RwLock<CalculatedSchedule>>,
+    rpc_leader_getter: JsonRpcLeaderGetter,
 }
 
 impl GrpcLeaderGetter {
-    pub fn new(leader_schedule: Arc<RwLock<CalculatedSchedule>>, epoch_data: EpochCache) -> Self {
+    pub fn new(
+        leader_schedule: Arc<RwLock<CalculatedSchedule>>,
+        epoch_data: EpochCache,
+        rpc_leader_getter: JsonRpcLeaderGetter,
+    ) -> Self {
         Self {
             leader_schedule,
             epoch_data,
+            rpc_leader_getter,
         }
     }
-}
 
-#[async_trait]
-impl LeaderFetcherInterface for GrpcLeaderGetter {
-    async fn get_slot_leaders(
+    async fn get_calculated_slot_leaders(
         &self,
         from: solana_sdk::slot_history::Slot,
         to: solana_sdk::slot_history::Slot,
@@ -35,17 +40,10 @@ impl LeaderFetcherInterface for GrpcLeaderGetter {
         let from_epoch = self.epoch_data.get_epoch_at_slot(from).epoch;
         let to_epoch = self.epoch_data.get_epoch_at_slot(to).epoch;
         let leader_schedule_data = self.leader_schedule.read().await;
-        let current_epoch = leader_schedule_data
-            .current
-            .as_ref()
-            .map(|e| e.epoch)
-            .unwrap_or(from_epoch);
-        let next_epoch = leader_schedule_data
-            .current
-            .as_ref()
-            .map(|e| e.epoch)
-            .unwrap_or(to_epoch)
-            + 1;
+        let Some(current_epoch) = leader_schedule_data.current.as_ref().map(|e| e.epoch) else {
+            bail!("leader schedule not calculated yet");
+        };
+        let next_epoch = current_epoch + 1;
         if from > to {
             bail!(
                 "invalid arguments for get_slot_leaders: from:{from} to:{to} from:{from} > to:{to}"
@@ -66,12 +64,20 @@ impl LeaderFetcherInterface for GrpcLeaderGetter {
             );
         }
 
-        let limit = to - from;
+        // from and to are both included
+        let limit = to - from + 1;
 
         let schedule = leader_schedule_data
             .get_slot_leaders(from, limit, self.epoch_data.get_epoch_schedule())
             .await
             .map_err(Error::msg)?;
+        if (schedule.len() as u64) < limit {
+            bail!(
+                "leader schedule of epoch {} not calculated yet, only {} leaders of {limit} found",
+                to_epoch,
+                schedule.len()
+            );
+        }
 
         Ok(schedule
             .into_iter()
@@ -83,3 +89,20 @@ impl LeaderFetcherInterface for GrpcLeaderGetter {
             .collect())
     }
 }
+
+#[async_trait]
+impl LeaderFetcherInterface for GrpcLeaderGetter {
+    async fn get_slot_leaders(
+        &self,
+        from: solana_sdk::slot_history::Slot,
+        to: solana_sdk::slot_history::Slot,
+    ) -> anyhow::Result<Vec<LeaderData>> {
+        match self.get_calculated_slot_leaders(from, to).await {
+            Ok(leaders) => Ok(leaders),
+            Err(err) => {
+                log::debug!("Using rpc for slot leaders from {from} to {to}: {err}");
+                self.rpc_leader_getter.get_slot_leaders(from, to).await
+            }
+        }
+    }
+}
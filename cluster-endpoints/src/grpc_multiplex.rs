@@ -21,14 +21,14 @@ use yellowstone_grpc_proto::geyser::{
     SubscribeRequest, SubscribeRequestFilterSlots, SubscribeUpdate,
 };
 
-struct BlockExtractor(CommitmentConfig);
+struct BlockExtractor(CommitmentConfig, bool);
 
 impl FromYellowstoneExtractor for BlockExtractor {
     type Target = ProducedBlock;
     fn map_yellowstone_update(&self, update: SubscribeUpdate) -> Option<(Slot, Self::Target)> {
         match update.update_oneof {
             Some(UpdateOneof::Block(update_block_message)) => {
-                let block = map_block_update(update_block_message, self.0);
+                let block = map_block_update(update_block_message, self.0, self.1);
                 Some((block.slot, block))
             }
             _ => None,
@@ -53,6 +53,7 @@ impl FromYellowstoneExtractor for BlockMetaHashExtractor {
 /// connect to multiple grpc sources to consume confirmed blocks and block status update
 pub fn create_grpc_multiplex_blocks_subscription(
     grpc_sources: Vec<GrpcSourceConfig>,
+    keep_transaction_meta: bool,
 ) -> (Receiver<ProducedBlock>, AnyhowJoinHandle) {
     info!("Setup grpc multiplexed blocks connection...");
     if grpc_sources.is_empty() {
@@ -102,7 +103,11 @@ pub fn create_grpc_multiplex_blocks_subscription(
                                             > slots_processed.first().cloned().unwrap_or_default())
                                 {
                                     confirmed_block_sender
-                                        .send(map_block_update(block, commitment_config))
+                                        .send(map_block_update(
+                                            block,
+                                            commitment_config,
+                                            keep_transaction_meta,
+                                        ))
                                         .context("Issue to send confirmed block")?;
                                     slots_processed.insert(slot);
                                     if slots_processed.len() > MAX_SIZE {
@@ -206,6 +211,7 @@ impl FromYellowstoneExtractor for crate::grpc_multiplex::SlotExtractor {
                 let slot = SlotNotification {
                     estimated_processed_slot: update_slot_message.slot,
                     processed_slot: update_slot_message.slot,
+                    parent_slot: update_slot_message.parent,
                 };
                 Some((update_slot_message.slot, slot))
             }
@@ -1,3 +1,4 @@
+use crate::grpc_account_streaming::create_grpc_account_streaming;
 use crate::grpc_multiplex::{
     create_grpc_multiplex_blocks_subscription, create_grpc_multiplex_slots_subscription,
 };
@@ -9,6 +10,7 @@ use anyhow::Context;
 use futures::StreamExt;
 use geyser_grpc_connector::grpc_subscription_autoreconnect::GrpcSourceConfig;
 use itertools::Itertools;
+use solana_account_decoder::parse_token::UiTokenAmount;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_lite_rpc_core::{
     encoding::BASE64,
@@ -22,15 +24,23 @@ use solana_sdk::{
     hash::Hash,
     instruction::CompiledInstruction,
     message::{
-        v0::{self, MessageAddressTableLookup},
+        legacy,
+        v0::{self, LoadedAddresses, MessageAddressTableLookup},
         MessageHeader, VersionedMessage,
     },
     pubkey::Pubkey,
     signature::Signature,
     transaction::TransactionError,
+    transaction_context::TransactionReturnData,
+};
+use solana_transaction_status::{
+    InnerInstruction, InnerInstructions, Reward, RewardType, TransactionStatusMeta,
+    TransactionTokenBalance,
+};
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc},
 };
-use solana_transaction_status::{Reward, RewardType};
-use std::{collections::HashMap, sync::Arc};
 use yellowstone_grpc_client::GeyserGrpcClient;
 
 use yellowstone_grpc_proto::prelude::{
@@ -41,6 +51,7 @@ use yellowstone_grpc_proto::prelude::{
 pub fn map_block_update(
     block: SubscribeUpdateBlock,
     commitment_config: CommitmentConfig,
+    keep_transaction_meta: bool,
 ) -> ProducedBlock {
     let txs: Vec<TransactionInfo> = block
         .transactions
@@ -69,7 +80,7 @@ pub fn map_block_update(
                 })
                 .collect_vec();
 
-            let err = meta.err.map(|x| {
+            let err = meta.err.as_ref().map(|x| {
                 bincode::deserialize::<TransactionError>(&x.err)
                     .expect("TransactionError should be deserialized")
             });
@@ -77,47 +88,66 @@ pub fn map_block_update(
             let signature = signatures[0];
             let compute_units_consumed = meta.compute_units_consumed;
 
-            let message = VersionedMessage::V0(v0::Message {
-                header: MessageHeader {
-                    num_required_signatures: header.num_required_signatures as u8,
-                    num_readonly_signed_accounts: header.num_readonly_signed_accounts as u8,
-                    num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u8,
-                },
-                account_keys: message
-                    .account_keys
-                    .into_iter()
-                    .map(|key| {
-                        let bytes: [u8; 32] =
-                            key.try_into().unwrap_or(Pubkey::default().to_bytes());
-                        Pubkey::new_from_array(bytes)
-                    })
-                    .collect(),
-                recent_blockhash: Hash::new(&message.recent_blockhash),
-                instructions: message
-                    .instructions
-                    .into_iter()
-                    .map(|ix| CompiledInstruction {
-                        program_id_index: ix.program_id_index as u8,
-                        accounts: ix.accounts,
-                        data: ix.data,
-                    })
-                    .collect(),
-                address_table_lookups: message
-                    .address_table_lookups
-                    .into_iter()
-                    .map(|table| {
-                        let bytes: [u8; 32] = table
-                            .account_key
-                            .try_into()
-                            .unwrap_or(Pubkey::default().to_bytes());
-                        MessageAddressTableLookup {
-                            account_key: Pubkey::new_from_array(bytes),
-                            writable_indexes: table.writable_indexes,
-                            readonly_indexes: table.readonly_indexes,
-                        }
-                    })
-                    .collect(),
-            });
+            let header = MessageHeader {
+                num_required_signatures: header.num_required_signatures as u8,
+                num_readonly_signed_accounts: header.num_readonly_signed_accounts as u8,
+                num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u8,
+            };
+            let account_keys = message
+                .account_keys
+                .into_iter()
+                .map(|key| {
+                    let bytes: [u8; 32] = key.try_into().unwrap_or(Pubkey::default().to_bytes());
+                    Pubkey::new_from_array(bytes)
+                })
+                .collect();
+            let recent_blockhash = Hash::new(&message.recent_blockhash);
+            let instructions = message
+                .instructions
+                .into_iter()
+                .map(|ix| CompiledInstruction {
+                    program_id_index: ix.program_id_index as u8,
+                    accounts: ix.accounts,
+                    data: ix.data,
+                })
+                .collect();
+
+            let message = if message.versioned {
+                VersionedMessage::V0(v0::Message {
+                    header,
+                    account_keys,
+                    recent_blockhash,
+                    instructions,
+                    address_table_lookups: message
+                        .address_table_lookups
+                        .into_iter()
+                        .map(|table| {
+                            let bytes: [u8; 32] = table
+                                .account_key
+                                .try_into()
+                                .unwrap_or(Pubkey::default().to_bytes());
+                            MessageAddressTableLookup {
+                                account_key: Pubkey::new_from_array(bytes),
+                                writable_indexes: table.writable_indexes,
+                                readonly_indexes: table.readonly_indexes,
+                            }
+                        })
+                        .collect(),
+                })
+            } else {
+                VersionedMessage::Legacy(legacy::Message {
+                    header,
+                    account_keys,
+                    recent_blockhash,
+                    instructions,
+                })
+            };
+
+            let meta = if keep_transaction_meta {
+                Some(map_transaction_status_meta(meta, err.clone()))
+            } else {
+                None
+            };
 
             let legacy_compute_budget: Option<(u32, Option<u64>)> =
                 message.instructions().iter().find_map(|i| {
@@ -188,31 +218,15 @@ pub fn map_block_update(
                 cu_consumed: compute_units_consumed,
                 recent_blockhash: message.recent_blockhash().to_string(),
                 message: BASE64.encode(message.serialize()),
+                signatures: signatures.iter().map(|s| s.to_string()).collect(),
+                meta,
             })
         })
         .collect();
 
-    let rewards = block.rewards.map(|rewards| {
-        rewards
-            .rewards
-            .into_iter()
-            .map(|reward| Reward {
-                pubkey: reward.pubkey.to_owned(),
-                lamports: reward.lamports,
-                post_balance: reward.post_balance,
-                reward_type: match reward.reward_type() {
-                    yellowstone_grpc_proto::prelude::RewardType::Unspecified => None,
-                    yellowstone_grpc_proto::prelude::RewardType::Fee => Some(RewardType::Fee),
-                    yellowstone_grpc_proto::prelude::RewardType::Rent => Some(RewardType::Rent),
-                    yellowstone_grpc_proto::prelude::RewardType::Staking => {
-                        Some(RewardType::Staking)
-                    }
-                    yellowstone_grpc_proto::prelude::RewardType::Voting => Some(RewardType::Voting),
-                },
-                commission: None,
-            })
-            .collect_vec()
-    });
+    let rewards = block
+        .rewards
+        .map(|rewards| rewards.rewards.into_iter().map(map_reward).collect_vec());
 
     let leader_id = if let Some(rewards) = &rewards {
         rewards
@@ -240,6 +254,118 @@ pub fn map_block_update(
     }
 }
 
+fn map_reward(reward: yellowstone_grpc_proto::prelude::Reward) -> Reward {
+    Reward {
+        reward_type: match reward.reward_type() {
+            yellowstone_grpc_proto::prelude::RewardType::Unspecified => None,
+            yellowstone_grpc_proto::prelude::RewardType::Fee => Some(RewardType::Fee),
+            yellowstone_grpc_proto::prelude::RewardType::Rent => Some(RewardType::Rent),
+            yellowstone_grpc_proto::prelude::RewardType::Staking => Some(RewardType::Staking),
+            yellowstone_grpc_proto::prelude::RewardType::Voting => Some(RewardType::Voting),
+        },
+        pubkey: reward.pubkey,
+        lamports: reward.lamports,
+        post_balance: reward.post_balance,
+        commission: reward.commission.parse().ok(),
+    }
+}
+
+fn map_pubkeys(keys: Vec<Vec<u8>>) -> Vec<Pubkey> {
+    keys.into_iter()
+        .map(|key| {
+            let bytes: [u8; 32] = key.try_into().unwrap_or(Pubkey::default().to_bytes());
+            Pubkey::new_from_array(bytes)
+        })
+        .collect()
+}
+
+fn map_token_balances(
+    token_balances: Vec<yellowstone_grpc_proto::prelude::TokenBalance>,
+) -> Vec<TransactionTokenBalance> {
+    token_balances
+        .into_iter()
+        .map(|balance| {
+            let ui_token_amount = balance.ui_token_amount.unwrap_or_default();
+            TransactionTokenBalance {
+                account_index: balance.account_index as u8,
+                mint: balance.mint,
+                ui_token_amount: UiTokenAmount {
+                    ui_amount: if (ui_token_amount.ui_amount - f64::default()).abs() > f64::EPSILON
+                    {
+                        Some(ui_token_amount.ui_amount)
+                    } else {
+                        None
+                    },
+                    decimals: ui_token_amount.decimals as u8,
+                    amount: ui_token_amount.amount,
+                    ui_amount_string: ui_token_amount.ui_amount_string,
+                },
+                owner: balance.owner,
+                program_id: balance.program_id,
+            }
+        })
+        .collect()
+}
+
+fn map_transaction_status_meta(
+    meta: yellowstone_grpc_proto::prelude::TransactionStatusMeta,
+    err: Option<TransactionError>,
+) -> TransactionStatusMeta {
+    let inner_instructions = (!meta.inner_instructions_none).then(|| {
+        meta.inner_instructions
+            .into_iter()
+            .map(|inner| InnerInstructions {
+                index: inner.index as u8,
+                instructions: inner
+                    .instructions
+                    .into_iter()
+                    .map(|ix| InnerInstruction {
+                        instruction: CompiledInstruction {
+                            program_id_index: ix.program_id_index as u8,
+                            accounts: ix.accounts,
+                            data: ix.data,
+                        },
+                        stack_height: ix.stack_height,
+                    })
+                    .collect(),
+            })
+            .collect()
+    });
+
+    let return_data = if meta.return_data_none {
+        None
+    } else {
+        meta.return_data.map(|return_data| {
+            let bytes: [u8; 32] = return_data
+                .program_id
+                .try_into()
+                .unwrap_or(Pubkey::default().to_bytes());
+            TransactionReturnData {
+                program_id: Pubkey::new_from_array(bytes),
+                data: return_data.data,
+            }
+        })
+    };
+
+    TransactionStatusMeta {
+        status: err.map_or(Ok(()), Err),
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions,
+        log_messages: (!meta.log_messages_none).then_some(meta.log_messages),
+        pre_token_balances: Some(map_token_balances(meta.pre_token_balances)),
+        post_token_balances: Some(map_token_balances(meta.post_token_balances)),
+        rewards: Some(meta.rewards.into_iter().map(map_reward).collect()),
+        loaded_addresses: LoadedAddresses {
+            writable: map_pubkeys(meta.loaded_writable_addresses),
+            readonly: map_pubkeys(meta.loaded_readonly_addresses),
+        },
+        return_data,
+        compute_units_consumed: meta.compute_units_consumed,
+    }
+}
+
 pub fn create_block_processing_task(
     grpc_addr: String,
     grpc_x_token: Option<String>,
@@ -307,6 +433,9 @@ pub fn create_block_processing_task(
 pub fn create_grpc_subscription(
     rpc_client: Arc<RpcClient>,
     grpc_sources: Vec<GrpcSourceConfig>,
+    keep_transaction_meta: bool,
+    // vote accounts are polled from the rpc while set
+    poll_vote_accounts: Arc<AtomicBool>,
 ) -> anyhow::Result<(EndpointStreaming, Vec<AnyhowJoinHandle>)> {
     let (cluster_info_sx, cluster_info_notifier) = tokio::sync::broadcast::channel(10);
     let (va_sx, vote_account_notifier) = tokio::sync::broadcast::channel(10);
@@ -316,7 +445,10 @@ pub fn create_grpc_subscription(
         create_grpc_multiplex_slots_subscription(grpc_sources.clone());
 
     let (block_multiplex_channel, jh_multiplex_blockstream) =
-        create_grpc_multiplex_blocks_subscription(grpc_sources);
+        create_grpc_multiplex_blocks_subscription(grpc_sources.clone(), keep_transaction_meta);
+
+    let (account_notifier, account_filters_sender, account_streaming_tasks) =
+        create_grpc_account_streaming(grpc_sources);
 
     grpc_inspect::block_debug_listen(
         block_multiplex_channel.resubscribe(),
@@ -328,19 +460,22 @@ pub fn create_grpc_subscription(
     );
 
     let cluster_info_polling =
-        poll_vote_accounts_and_cluster_info(rpc_client, cluster_info_sx, va_sx);
+        poll_vote_accounts_and_cluster_info(rpc_client, cluster_info_sx, va_sx, poll_vote_accounts);
 
     let streamers = EndpointStreaming {
         blocks_notifier: block_multiplex_channel,
         slot_notifier: slot_multiplex_channel,
         cluster_info_notifier,
         vote_account_notifier,
+        account_notifier: Some(account_notifier),
+        account_filters_sender: Some(account_filters_sender),
     };
 
-    let endpoint_tasks = vec![
+    let mut endpoint_tasks = vec![
         jh_multiplex_slotstream,
         jh_multiplex_blockstream,
         cluster_info_polling,
     ];
+    endpoint_tasks.extend(account_streaming_tasks);
     Ok((streamers, endpoint_tasks))
 }
@@ -8,10 +8,11 @@ use crate::{
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_lite_rpc_core::AnyhowJoinHandle;
 use solana_sdk::commitment_config::CommitmentConfig;
-use std::sync::Arc;
+use std::sync::{atomic::AtomicBool, Arc};
 
 pub fn create_json_rpc_polling_subscription(
     rpc_client: Arc<RpcClient>,
+    keep_transaction_meta: bool,
 ) -> anyhow::Result<(EndpointStreaming, Vec<AnyhowJoinHandle>)> {
     let (slot_sx, slot_notifier) = tokio::sync::broadcast::channel(10);
     let (block_sx, blocks_notifier) = tokio::sync::broadcast::channel(10);
@@ -21,12 +22,20 @@ pub fn create_json_rpc_polling_subscription(
     let mut endpoint_tasks =
         poll_slots(rpc_client.clone(), CommitmentConfig::processed(), slot_sx)?;
 
-    let mut block_polling_tasks =
-        poll_block(rpc_client.clone(), block_sx, slot_notifier.resubscribe());
+    let mut block_polling_tasks = poll_block(
+        rpc_client.clone(),
+        block_sx,
+        slot_notifier.resubscribe(),
+        keep_transaction_meta,
+    );
     endpoint_tasks.append(&mut block_polling_tasks);
 
-    let cluster_info_polling =
-        poll_vote_accounts_and_cluster_info(rpc_client, cluster_info_sx, va_sx);
+    let cluster_info_polling = poll_vote_accounts_and_cluster_info(
+        rpc_client,
+        cluster_info_sx,
+        va_sx,
+        Arc::new(AtomicBool::new(true)),
+    );
     endpoint_tasks.push(cluster_info_polling);
 
     let streamers = EndpointStreaming {
@@ -34,6 +43,8 @@ pub fn create_json_rpc_polling_subscription(
         slot_notifier,
         cluster_info_notifier,
         vote_account_notifier,
+        account_notifier: None,
+        account_filters_sender: None,
     };
     Ok((streamers, endpoint_tasks))
 }
@@ -20,6 +20,7 @@ pub async fn process_block(
     rpc_client: &RpcClient,
     slot: Slot,
     commitment_config: CommitmentConfig,
+    keep_transaction_meta: bool,
 ) -> Option<ProducedBlock> {
     let block = rpc_client
         .get_block_with_config(
@@ -33,15 +34,16 @@ pub async fn process_block(
             },
         )
         .await;
-    block
-        .ok()
-        .map(|block| ProducedBlock::from_ui_block(block, slot, commitment_config))
+    block.ok().map(|block| {
+        ProducedBlock::from_ui_block(block, slot, commitment_config, keep_transaction_meta)
+    })
 }
 
 pub fn poll_block(
     rpc_client: Arc<RpcClient>,
     block_notification_sender: Sender<ProducedBlock>,
     slot_notification: Receiver<SlotNotification>,
+    keep_transaction_meta: bool,
 ) -> Vec<AnyhowJoinHandle> {
     let mut tasks: Vec<AnyhowJoinHandle> = vec![];
 
@@ -61,8 +63,13 @@ pub fn poll_block(
                     .recv()
                     .await
                     .context("Recv error on block channel")?;
-                let processed_block =
-                    process_block(rpc_client.as_ref(), slot, commitment_config).await;
+                let processed_block = process_block(
+                    rpc_client.as_ref(),
+                    slot,
+                    commitment_config,
+                    keep_transaction_meta,
+                )
+                .await;
                 match processed_block {
                     Some(processed_block) => {
                         block_notification_sender
@@ -72,6 +72,7 @@ pub fn poll_slots(
                             .send(SlotNotification {
                                 processed_slot: current_slot,
                                 estimated_processed_slot: estimated_slot,
+                                parent_slot: None,
                             })
                             .context("Cannot send slot notification")?;
                     }
@@ -89,6 +90,7 @@ pub fn poll_slots(
                             .send(SlotNotification {
                                 processed_slot: current_slot,
                                 estimated_processed_slot: estimated_slot,
+                                parent_slot: None,
                             })
                             .context("Cannot send slot notification")?;
                     }
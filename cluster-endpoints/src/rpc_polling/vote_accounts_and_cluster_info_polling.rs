@@ -2,13 +2,22 @@ use anyhow::Context;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_lite_rpc_core::AnyhowJoinHandle;
 use solana_rpc_client_api::response::{RpcContactInfo, RpcVoteAccountStatus};
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::sync::broadcast::Sender;
 
+// vote accounts are polled until the stake and vote loop computed them from geyser and cleared
+// the poll flag, the sender is kept alive so the vote account stream stays open
 pub fn poll_vote_accounts_and_cluster_info(
     rpc_client: Arc<RpcClient>,
     contact_info_sender: Sender<Vec<RpcContactInfo>>,
     vote_account_sender: Sender<RpcVoteAccountStatus>,
+    poll_vote_accounts: Arc<AtomicBool>,
 ) -> AnyhowJoinHandle {
     tokio::spawn(async move {
         loop {
@@ -17,10 +26,12 @@ pub fn poll_vote_accounts_and_cluster_info(
                     .send(cluster_nodes)
                     .context("Should be able to send cluster info")?;
             }
-            if let Ok(vote_accounts) = rpc_client.get_vote_accounts().await {
-                vote_account_sender
-                    .send(vote_accounts)
-                    .context("Should be able to send vote accounts")?;
+            if poll_vote_accounts.load(Ordering::Relaxed) {
+                if let Ok(vote_accounts) = rpc_client.get_vote_accounts().await {
+                    vote_account_sender
+                        .send(vote_accounts)
+                        .context("Should be able to send vote accounts")?;
+                }
             }
             tokio::time::sleep(Duration::from_secs(600)).await;
         }
@@ -0,0 +1,234 @@
+use crate::{commitment_utils::Commitment, structures::account_data::AccountData};
+use dashmap::{DashMap, DashSet};
+use solana_account_decoder::UiAccountEncoding;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot};
+use std::{
+    collections::{BTreeSet, HashSet},
+    sync::Arc,
+};
+
+// latest state of an account at processed, confirmed and finalized
+type AccountByCommitment = [Option<AccountData>; 3];
+
+/// Accounts owned by the configured programs.
+///
+/// The store is seeded with the program accounts fetched from the rpc and kept up to date with
+/// the account updates of the cluster endpoint, the most recent update by slot and write version wins.
+#[derive(Clone, Default)]
+pub struct AccountStore {
+    programs: Arc<BTreeSet<Pubkey>>,
+    accounts: Arc<DashMap<Pubkey, AccountByCommitment>>,
+    // accounts that have been owned by each program, the current owner is checked when read
+    program_accounts: Arc<DashMap<Pubkey, HashSet<Pubkey>>>,
+    // programs whose accounts have all been loaded
+    seeded_programs: Arc<DashSet<Pubkey>>,
+}
+
+impl AccountStore {
+    pub fn new(programs: BTreeSet<Pubkey>) -> Self {
+        Self {
+            programs: Arc::new(programs),
+            ..Default::default()
+        }
+    }
+
+    /// programs whose accounts are cached
+    pub fn programs(&self) -> &BTreeSet<Pubkey> {
+        &self.programs
+    }
+
+    /// accounts that have been owned by a cached program
+    pub fn cached_accounts(&self) -> Vec<Pubkey> {
+        self.accounts.iter().map(|entry| *entry.key()).collect()
+    }
+
+    /// true when all the accounts of the program are cached
+    pub fn is_program_seeded(&self, program_id: &Pubkey) -> bool {
+        self.seeded_programs.contains(program_id)
+    }
+
+    /// Update the account at the commitment, the lower commitments are updated too when
+    /// the update is more recent. Returns false if the update is ignored.
+    pub fn update_account(&self, data: AccountData, commitment: Commitment) -> bool {
+        let owner = data.account.owner;
+        if self.programs.contains(&owner) {
+            self.program_accounts
+                .entry(owner)
+                .or_default()
+                .insert(data.pubkey);
+        } else if !self.accounts.contains_key(&data.pubkey) {
+            // not owned by a cached program
+            return false;
+        }
+
+        let mut by_commitment = self.accounts.entry(data.pubkey).or_default();
+        let mut updated = false;
+        for account in by_commitment.iter_mut().take(commitment as usize + 1) {
+            let is_more_recent = account.as_ref().map_or(true, |account| {
+                (data.updated_slot, data.write_version)
+                    > (account.updated_slot, account.write_version)
+            });
+            if is_more_recent {
+                *account = Some(data.clone());
+                updated = true;
+            }
+        }
+        updated
+    }
+
+    /// Account at the commitment, none if the account is not cached.
+    /// Accounts no longer owned by a cached program are not streamed anymore and are not returned,
+    /// closed accounts included.
+    pub fn get_account(&self, pubkey: &Pubkey, commitment: Commitment) -> Option<AccountData> {
+        self.accounts
+            .get(pubkey)
+            .and_then(|by_commitment| by_commitment[commitment as usize].clone())
+            .filter(|data| self.programs.contains(&data.account.owner))
+    }
+
+    /// Accounts owned by the program at the commitment, none if the program is not seeded
+    pub fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        commitment: Commitment,
+    ) -> Option<Vec<AccountData>> {
+        if !self.is_program_seeded(program_id) {
+            return None;
+        }
+        let Some(pubkeys) = self.program_accounts.get(program_id) else {
+            return Some(vec![]);
+        };
+        Some(
+            pubkeys
+                .iter()
+                .filter_map(|pubkey| self.get_account(pubkey, commitment))
+                .filter(|data| data.account.owner == *program_id && data.account.lamports > 0)
+                .collect(),
+        )
+    }
+
+    /// Load the accounts of the configured programs with getProgramAccounts.
+    ///
+    /// The account updates have to be streamed before, the accounts changed during the loading
+    /// are then replaced by their updates. The programs already loaded are skipped when retried.
+    pub async fn load_program_accounts(&self, rpc_client: &RpcClient) -> anyhow::Result<()> {
+        for program_id in self.programs.iter() {
+            if self.is_program_seeded(program_id) {
+                continue;
+            }
+            // the accounts are at least as recent as the finalized slot fetched before them
+            let slot: Slot = rpc_client
+                .get_slot_with_commitment(CommitmentConfig::finalized())
+                .await?;
+            let accounts = rpc_client
+                .get_program_accounts_with_config(
+                    program_id,
+                    RpcProgramAccountsConfig {
+                        account_config: RpcAccountInfoConfig {
+                            encoding: Some(UiAccountEncoding::Base64Zstd),
+                            commitment: Some(CommitmentConfig::finalized()),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            log::info!("Loaded {} accounts of program {program_id}", accounts.len());
+            for (pubkey, account) in accounts {
+                self.update_account(
+                    AccountData {
+                        pubkey,
+                        account: Arc::new(account),
+                        updated_slot: slot,
+                        write_version: 0,
+                    },
+                    Commitment::Finalized,
+                );
+            }
+            self.seeded_programs.insert(*program_id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::account::Account;
+
+    fn account_data(pubkey: Pubkey, owner: Pubkey, lamports: u64, slot: Slot) -> AccountData {
+        AccountData {
+            pubkey,
+            account: Arc::new(Account {
+                lamports,
+                owner,
+                ..Default::default()
+            }),
+            updated_slot: slot,
+            write_version: 0,
+        }
+    }
+
+    #[test]
+    fn account_store_keeps_most_recent_update() {
+        let (program_id, pubkey) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let store = AccountStore::new(BTreeSet::from([program_id]));
+
+        assert!(!store.update_account(
+            account_data(pubkey, Pubkey::new_unique(), 1, 10),
+            Commitment::Processed
+        ));
+        assert!(store.get_account(&pubkey, Commitment::Processed).is_none());
+
+        assert!(store.update_account(
+            account_data(pubkey, program_id, 1, 10),
+            Commitment::Finalized
+        ));
+        assert!(store.update_account(
+            account_data(pubkey, program_id, 2, 12),
+            Commitment::Processed
+        ));
+        // older than the processed update
+        assert!(store.update_account(
+            account_data(pubkey, program_id, 3, 11),
+            Commitment::Confirmed
+        ));
+
+        let lamports = |commitment| {
+            store
+                .get_account(&pubkey, commitment)
+                .unwrap()
+                .account
+                .lamports
+        };
+        assert_eq!(lamports(Commitment::Processed), 2);
+        assert_eq!(lamports(Commitment::Confirmed), 3);
+        assert_eq!(lamports(Commitment::Finalized), 1);
+
+        assert!(store
+            .get_program_accounts(&program_id, Commitment::Processed)
+            .is_none());
+        store.seeded_programs.insert(program_id);
+        assert_eq!(
+            store
+                .get_program_accounts(&program_id, Commitment::Processed)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // closed accounts are no longer owned by the program
+        assert!(store.update_account(
+            account_data(pubkey, Pubkey::default(), 0, 13),
+            Commitment::Processed,
+        ));
+        assert!(store
+            .get_program_accounts(&program_id, Commitment::Processed)
+            .unwrap()
+            .is_empty());
+        assert!(store.get_account(&pubkey, Commitment::Processed).is_none());
+        assert_eq!(lamports(Commitment::Confirmed), 3);
+    }
+}
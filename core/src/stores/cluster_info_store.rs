@@ -1,13 +1,23 @@
 use dashmap::DashMap;
 use solana_rpc_client_api::response::RpcContactInfo;
-use solana_sdk::pubkey::Pubkey;
-use std::{str::FromStr, sync::Arc};
+use solana_sdk::{pubkey::Pubkey, quic::QUIC_PORT_OFFSET};
+use std::{
+    net::SocketAddr,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use crate::types::ClusterInfoStream;
 
 #[derive(Debug, Clone, Default)]
 pub struct ClusterInfo {
     pub cluster_nodes: Arc<DashMap<Pubkey, Arc<RpcContactInfo>>>,
+    // unix timestamp in seconds of the last update, 0 if never updated
+    last_update: Arc<AtomicU64>,
 }
 
 impl ClusterInfo {
@@ -24,6 +34,23 @@ impl ClusterInfo {
                 self.cluster_nodes.insert(pubkey, Arc::new(x.clone()));
             }
         });
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        self.last_update.store(now, Ordering::Relaxed);
         Ok(())
     }
+
+    /// tpu quic address of the node, its tpu port with the quic port offset
+    pub fn get_tpu_quic_address(&self, pubkey: &Pubkey) -> Option<SocketAddr> {
+        let mut addr = self.cluster_nodes.get(pubkey)?.tpu?;
+        addr.set_port(addr.port() + QUIC_PORT_OFFSET);
+        Some(addr)
+    }
+
+    /// unix timestamp in seconds of the last cluster info update
+    pub fn last_update(&self) -> Option<u64> {
+        match self.last_update.load(Ordering::Relaxed) {
+            0 => None,
+            last_update => Some(last_update),
+        }
+    }
 }
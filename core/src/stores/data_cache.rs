@@ -8,13 +8,16 @@ use tokio::sync::RwLock;
 
 use crate::{
     stores::{
-        block_information_store::BlockInformationStore, cluster_info_store::ClusterInfo,
-        subscription_store::SubscriptionStore, tx_store::TxStore,
+        account_store::AccountStore, block_information_store::BlockInformationStore,
+        cluster_info_store::ClusterInfo, prioritization_fees_store::PrioritizationFeesStore,
+        subscription_store::SubscriptionStore, tx_lifecycle_store::TxLifecycleStore,
+        tx_store::TxStore,
     },
     structures::{
         epoch::{Epoch, EpochCache},
         identity_stakes::IdentityStakes,
         slot_notification::{AtomicSlot, SlotNotification},
+        transaction_lifecycle::TransactionLifecycleStatus,
         transaction_sent_info::SentTransactionInfo,
     },
 };
@@ -39,6 +42,9 @@ pub struct DataCache {
     pub cluster_info: ClusterInfo,
     pub epoch_data: EpochCache,
     pub leader_schedule: Arc<RwLock<CalculatedSchedule>>,
+    pub prioritization_fees: PrioritizationFeesStore,
+    pub accounts: AccountStore,
+    pub tx_lifecycles: TxLifecycleStore,
 }
 
 impl DataCache {
@@ -48,7 +54,15 @@ impl DataCache {
             .get_latest_block_info(CommitmentConfig::finalized())
             .await;
         self.block_information_store.clean().await;
-        self.txs.clean(block_info.block_height);
+        for signature in self.txs.clean(block_info.block_height) {
+            self.tx_lifecycles.expired(&signature);
+            self.tx_subs.notify_transaction_lifecycle(
+                &signature,
+                TransactionLifecycleStatus::Expired,
+                block_info.slot,
+            );
+        }
+        self.tx_lifecycles.clean(block_info.block_height);
 
         self.tx_subs.clean(ttl_duration);
     }
@@ -90,6 +104,9 @@ impl DataCache {
             },
             epoch_data: EpochCache::new_for_tests(),
             leader_schedule: Arc::new(RwLock::new(CalculatedSchedule::default())),
+            prioritization_fees: PrioritizationFeesStore::default(),
+            accounts: AccountStore::default(),
+            tx_lifecycles: TxLifecycleStore::default(),
         }
     }
 }
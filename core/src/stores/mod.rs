@@ -1,7 +1,10 @@
 // this mod will contain all the different stores that are used by lite-rpc
 
+pub mod account_store;
 pub mod block_information_store;
 pub mod cluster_info_store;
 pub mod data_cache;
+pub mod prioritization_fees_store;
 pub mod subscription_store;
+pub mod tx_lifecycle_store;
 pub mod tx_store;
@@ -0,0 +1,243 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+};
+
+use solana_rpc_client_api::response::RpcPrioritizationFee;
+use solana_sdk::{pubkey::Pubkey, slot_history::Slot};
+use tokio::sync::RwLock;
+
+use crate::structures::produced_block::ProducedBlock;
+
+// same number of slots as the prioritization fee cache of the validator
+pub const NB_SLOTS_PRIORITIZATION_FEES: usize = 150;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransactionFee {
+    pub prioritization_fee: u64,
+    pub cu_consumed: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SlotPrioritizationFees {
+    // minimum prioritization fee of the non vote transactions of the block
+    pub min_fee: u64,
+    // minimum prioritization fee of the transactions write locking each account
+    pub account_min_fees: HashMap<Pubkey, u64>,
+    // fees of the non vote transactions of the block
+    pub transaction_fees: Vec<TransactionFee>,
+    // index in transaction_fees of the transactions write locking each account
+    pub account_transactions: HashMap<Pubkey, Vec<usize>>,
+}
+
+/// percentiles of the prioritization fees over a window of slots
+#[derive(Debug, Clone, Default)]
+pub struct PrioritizationFeePercentiles {
+    pub first_slot: Slot,
+    pub last_slot: Slot,
+    pub nb_transactions: usize,
+    // one value per requested percentile, empty when no transactions were found
+    pub fees: Vec<u64>,
+}
+
+impl SlotPrioritizationFees {
+    pub fn from_block(block: &ProducedBlock) -> Self {
+        let mut min_fee = None;
+        let mut account_min_fees = HashMap::<Pubkey, u64>::new();
+        let mut transaction_fees = vec![];
+        let mut account_transactions = HashMap::<Pubkey, Vec<usize>>::new();
+        for tx in block.transactions.iter().filter(|tx| !tx.is_vote()) {
+            let fee = tx.prioritization_fees.unwrap_or_default();
+            min_fee = Some(min_fee.map_or(fee, |min_fee: u64| min_fee.min(fee)));
+            let index = transaction_fees.len();
+            transaction_fees.push(TransactionFee {
+                prioritization_fee: fee,
+                cu_consumed: tx.cu_consumed.unwrap_or_default(),
+            });
+
+            let writable_accounts = match tx.writable_account_keys() {
+                Ok(writable_accounts) => writable_accounts,
+                Err(e) => {
+                    log::warn!(
+                        "Error decoding writable accounts of transaction {}: {e:?}",
+                        tx.signature
+                    );
+                    continue;
+                }
+            };
+            for account in writable_accounts {
+                account_min_fees
+                    .entry(account)
+                    .and_modify(|min_fee| *min_fee = (*min_fee).min(fee))
+                    .or_insert(fee);
+                account_transactions.entry(account).or_default().push(index);
+            }
+        }
+        Self {
+            min_fee: min_fee.unwrap_or_default(),
+            account_min_fees,
+            transaction_fees,
+            account_transactions,
+        }
+    }
+
+    // the fee needed to land in the slot while write locking all the accounts
+    pub fn get_fee(&self, accounts: &[Pubkey]) -> u64 {
+        accounts
+            .iter()
+            .filter_map(|account| self.account_min_fees.get(account))
+            .fold(self.min_fee, |fee, account_fee| fee.max(*account_fee))
+    }
+
+    // fees of the transactions write locking any of the accounts, all the transactions if none
+    pub fn get_transaction_fees(&self, accounts: &[Pubkey]) -> Vec<TransactionFee> {
+        if accounts.is_empty() {
+            return self.transaction_fees.clone();
+        }
+        let indexes: BTreeSet<usize> = accounts
+            .iter()
+            .filter_map(|account| self.account_transactions.get(account))
+            .flatten()
+            .copied()
+            .collect();
+        indexes
+            .into_iter()
+            .map(|index| self.transaction_fees[index])
+            .collect()
+    }
+}
+
+/// percentiles of the fees weighted by the compute units consumed by each transaction,
+/// none if no compute units were consumed
+pub fn cu_weighted_percentiles(
+    mut transaction_fees: Vec<TransactionFee>,
+    percentiles: &[u64],
+) -> Option<Vec<u64>> {
+    transaction_fees.sort_by_key(|fee| fee.prioritization_fee);
+    let total_cu: u128 = transaction_fees
+        .iter()
+        .map(|fee| fee.cu_consumed as u128)
+        .sum();
+    if total_cu == 0 {
+        return None;
+    }
+
+    Some(
+        percentiles
+            .iter()
+            .map(|percentile| {
+                let threshold = total_cu * (*percentile).min(100) as u128;
+                let mut cumulated_cu = 0u128;
+                transaction_fees
+                    .iter()
+                    .find(|fee| {
+                        cumulated_cu += fee.cu_consumed as u128;
+                        // compared in percent to avoid rounding
+                        fee.cu_consumed > 0 && cumulated_cu * 100 >= threshold
+                    })
+                    .map_or(0, |fee| fee.prioritization_fee)
+            })
+            .collect(),
+    )
+}
+
+/// prioritization fees of the most recent slots
+#[derive(Clone, Default)]
+pub struct PrioritizationFeesStore {
+    slots: Arc<RwLock<BTreeMap<Slot, SlotPrioritizationFees>>>,
+}
+
+impl PrioritizationFeesStore {
+    pub async fn add_block(&self, block: &ProducedBlock) {
+        // fees of a slot do not change with its commitment
+        if self.slots.read().await.contains_key(&block.slot) {
+            return;
+        }
+
+        let slot_fees = SlotPrioritizationFees::from_block(block);
+        let mut slots = self.slots.write().await;
+        slots.insert(block.slot, slot_fees);
+        while slots.len() > NB_SLOTS_PRIORITIZATION_FEES {
+            slots.pop_first();
+        }
+    }
+
+    pub async fn get_prioritization_fees(&self, accounts: &[Pubkey]) -> Vec<RpcPrioritizationFee> {
+        self.slots
+            .read()
+            .await
+            .iter()
+            .map(|(slot, slot_fees)| RpcPrioritizationFee {
+                slot: *slot,
+                prioritization_fee: slot_fees.get_fee(accounts),
+            })
+            .collect()
+    }
+
+    /// cu weighted percentiles of the fees paid in the last `nb_slots` slots
+    pub async fn get_fee_percentiles(
+        &self,
+        accounts: &[Pubkey],
+        nb_slots: usize,
+        percentiles: &[u64],
+    ) -> PrioritizationFeePercentiles {
+        let slots = self.slots.read().await;
+        let window = slots.iter().rev().take(nb_slots);
+        let mut result = PrioritizationFeePercentiles::default();
+        let mut transaction_fees = vec![];
+        for (slot, slot_fees) in window {
+            if result.last_slot == 0 {
+                result.last_slot = *slot;
+            }
+            result.first_slot = *slot;
+            transaction_fees.extend(slot_fees.get_transaction_fees(accounts));
+        }
+        drop(slots);
+
+        result.nb_transactions = transaction_fees.len();
+        result.fees = cu_weighted_percentiles(transaction_fees, percentiles).unwrap_or_default();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slot_fee_is_the_max_of_block_and_accounts_min_fees() {
+        let (account_a, account_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let slot_fees = SlotPrioritizationFees {
+            min_fee: 10,
+            account_min_fees: HashMap::from([(account_a, 100), (account_b, 5)]),
+            ..Default::default()
+        };
+        assert_eq!(slot_fees.get_fee(&[]), 10);
+        assert_eq!(slot_fees.get_fee(&[account_b]), 10);
+        assert_eq!(slot_fees.get_fee(&[account_a, account_b]), 100);
+        assert_eq!(slot_fees.get_fee(&[Pubkey::new_unique()]), 10);
+    }
+
+    #[test]
+    fn percentiles_are_weighted_by_consumed_cu() {
+        let transaction_fees = vec![
+            TransactionFee {
+                prioritization_fee: 1000,
+                cu_consumed: 100_000,
+            },
+            TransactionFee {
+                prioritization_fee: 10,
+                cu_consumed: 300_000,
+            },
+            TransactionFee {
+                prioritization_fee: 50_000,
+                cu_consumed: 0,
+            },
+        ];
+        assert_eq!(
+            cu_weighted_percentiles(transaction_fees, &[25, 50, 75, 90, 99]),
+            Some(vec![10, 10, 10, 1000, 1000])
+        );
+        assert_eq!(cu_weighted_percentiles(vec![], &[50]), None);
+    }
+}
@@ -1,16 +1,39 @@
-use crate::{structures::produced_block::TransactionInfo, types::SubscptionHanderSink};
+use crate::{
+    stores::tx_lifecycle_store::TxLifecycleStore,
+    structures::{
+        produced_block::TransactionInfo,
+        transaction_lifecycle::{TransactionLifecycleEvent, TransactionLifecycleStatus},
+    },
+    types::SubscptionHanderSink,
+};
 use dashmap::DashMap;
 use solana_sdk::{
     commitment_config::{CommitmentConfig, CommitmentLevel},
     slot_history::Slot,
 };
 use std::{sync::Arc, time::Duration};
-use tokio::time::Instant;
+use tokio::{sync::broadcast, time::Instant};
+
+// lifecycle events buffered for each subscriber before the slowest ones start to skip some
+const TRANSACTION_LIFECYCLE_CAPACITY: usize = 10_000;
+
+/// sink, time of the subscription and whether the `receivedSignature` notification is pending
+pub type SignatureSubscriber = (SubscptionHanderSink, Instant, bool);
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct SubscriptionStore {
-    pub signature_subscribers:
-        Arc<DashMap<(String, CommitmentConfig), (SubscptionHanderSink, Instant)>>,
+    pub signature_subscribers: Arc<DashMap<(String, CommitmentConfig), SignatureSubscriber>>,
+    /// state changes of the transactions sent through lite-rpc
+    pub transaction_lifecycle: broadcast::Sender<TransactionLifecycleEvent>,
+}
+
+impl Default for SubscriptionStore {
+    fn default() -> Self {
+        Self {
+            signature_subscribers: Arc::default(),
+            transaction_lifecycle: broadcast::channel(TRANSACTION_LIFECYCLE_CAPACITY).0,
+        }
+    }
 }
 
 impl SubscriptionStore {
@@ -30,15 +53,25 @@ impl SubscriptionStore {
         }
     }
 
+    /// Subscribe to the signature, `receivedSignature` is notified right away when the transaction
+    /// has already been forwarded. Forwards are recorded in the lifecycles before `notify_received`
+    /// is called so a concurrent subscription is notified once by either of them.
     pub fn signature_subscribe(
         &self,
         signature: String,
         commitment_config: CommitmentConfig,
         sink: SubscptionHanderSink,
+        enable_received_notification: bool,
+        tx_lifecycles: &TxLifecycleStore,
     ) {
         let commitment_config = Self::get_supported_commitment_config(commitment_config);
-        self.signature_subscribers
-            .insert((signature, commitment_config), (sink, Instant::now()));
+        self.signature_subscribers.insert(
+            (signature.clone(), commitment_config),
+            (sink, Instant::now(), enable_received_notification),
+        );
+        if let Some(forwarded_slot) = tx_lifecycles.first_forward_slot(&signature) {
+            self.notify_received(&signature, forwarded_slot);
+        }
     }
 
     pub fn signature_un_subscribe(&self, signature: String, commitment_config: CommitmentConfig) {
@@ -53,7 +86,7 @@ impl SubscriptionStore {
         transaction_info: &TransactionInfo,
         commitment_config: CommitmentConfig,
     ) {
-        if let Some((_sig, (sink, _))) = self
+        if let Some((_sig, (sink, _, _))) = self
             .signature_subscribers
             .remove(&(transaction_info.signature.clone(), commitment_config))
         {
@@ -63,12 +96,155 @@ impl SubscriptionStore {
         }
     }
 
+    /// Send the `receivedSignature` notification to the subscribers who enabled it,
+    /// once the transaction has been forwarded to the tpu for the first time.
+    /// The notifications are sent in the background so a slow client never delays the forwarding.
+    pub fn notify_received(&self, signature: &str, slot: Slot) {
+        let sinks: Vec<SubscptionHanderSink> =
+            [CommitmentConfig::confirmed(), CommitmentConfig::finalized()]
+                .into_iter()
+                .filter_map(|commitment_config| {
+                    let mut subscriber = self
+                        .signature_subscribers
+                        .get_mut(&(signature.to_string(), commitment_config))?;
+                    let (sink, _, received_notification_pending) = subscriber.value_mut();
+                    std::mem::take(received_notification_pending).then(|| sink.clone())
+                })
+                .collect();
+        if sinks.is_empty() {
+            return;
+        }
+        tokio::spawn(async move {
+            for sink in sinks {
+                sink.send(slot, serde_json::json!("receivedSignature"))
+                    .await;
+            }
+        });
+    }
+
+    pub fn subscribe_transaction_lifecycle(
+        &self,
+    ) -> broadcast::Receiver<TransactionLifecycleEvent> {
+        self.transaction_lifecycle.subscribe()
+    }
+
+    pub fn notify_transaction_lifecycle(
+        &self,
+        signature: &str,
+        status: TransactionLifecycleStatus,
+        slot: Slot,
+    ) {
+        if self.transaction_lifecycle.receiver_count() == 0 {
+            return;
+        }
+        // no receiver left when the last subscriber unsubscribes meanwhile
+        let _ = self.transaction_lifecycle.send(TransactionLifecycleEvent {
+            signature: signature.to_string(),
+            status,
+            slot,
+        });
+    }
+
     pub fn clean(&self, ttl_duration: Duration) {
         self.signature_subscribers
-            .retain(|_k, (sink, instant)| !sink.is_closed() && instant.elapsed() < ttl_duration);
+            .retain(|_k, (sink, instant, _)| !sink.is_closed() && instant.elapsed() < ttl_duration);
     }
 
     pub fn number_of_subscribers(&self) -> usize {
         self.signature_subscribers.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::subscription_sink::SubscriptionSink;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestSink {
+        messages: Mutex<Vec<(Slot, serde_json::Value)>>,
+    }
+
+    #[async_trait]
+    impl SubscriptionSink for TestSink {
+        async fn send(&self, slot: Slot, message: serde_json::Value) {
+            self.messages.lock().unwrap().push((slot, message));
+        }
+
+        fn is_closed(&self) -> bool {
+            false
+        }
+    }
+
+    async fn received_notifications(sink: &TestSink) -> Vec<(Slot, serde_json::Value)> {
+        // the notifications are sent in the background
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        sink.messages.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn received_notification_sent_on_forward() {
+        let (subscriptions, tx_lifecycles) =
+            (SubscriptionStore::default(), TxLifecycleStore::default());
+        tx_lifecycles.received("sig".to_string(), 100, 0);
+        let sink = Arc::new(TestSink::default());
+        subscriptions.signature_subscribe(
+            "sig".to_string(),
+            CommitmentConfig::confirmed(),
+            sink.clone(),
+            true,
+            &tx_lifecycles,
+        );
+        assert!(received_notifications(&sink).await.is_empty());
+
+        tx_lifecycles
+            .forward("sig", 10, vec![], false, |_| Ok::<(), ()>(()))
+            .unwrap();
+        subscriptions.notify_received("sig", 10);
+        // only the first forward is notified
+        subscriptions.notify_received("sig", 12);
+        assert_eq!(
+            received_notifications(&sink).await,
+            vec![(10, serde_json::json!("receivedSignature"))]
+        );
+    }
+
+    #[tokio::test]
+    async fn received_notification_sent_on_late_subscription() {
+        let (subscriptions, tx_lifecycles) =
+            (SubscriptionStore::default(), TxLifecycleStore::default());
+        tx_lifecycles.received("sig".to_string(), 100, 0);
+        tx_lifecycles
+            .forward("sig", 10, vec![], false, |_| Ok::<(), ()>(()))
+            .unwrap();
+        // nobody subscribed yet
+        subscriptions.notify_received("sig", 10);
+
+        let sink = Arc::new(TestSink::default());
+        subscriptions.signature_subscribe(
+            "sig".to_string(),
+            CommitmentConfig::finalized(),
+            sink.clone(),
+            true,
+            &tx_lifecycles,
+        );
+        subscriptions.notify_received("sig", 12);
+        assert_eq!(
+            received_notifications(&sink).await,
+            vec![(10, serde_json::json!("receivedSignature"))]
+        );
+
+        // not notified when the received notification is disabled
+        let sink = Arc::new(TestSink::default());
+        subscriptions.signature_subscribe(
+            "sig".to_string(),
+            CommitmentConfig::confirmed(),
+            sink.clone(),
+            false,
+            &tx_lifecycles,
+        );
+        assert!(received_notifications(&sink).await.is_empty());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::structures::transaction_lifecycle::{
+    QuicSendOutcome, TransactionExpiryReason, TransactionForward, TransactionLifecycle,
+};
+use chrono::Utc;
+use dashmap::DashMap;
+use solana_sdk::{clock::MAX_RECENT_BLOCKHASHES, pubkey::Pubkey, slot_history::Slot};
+use solana_transaction_status::TransactionConfirmationStatus;
+use std::{collections::HashMap, sync::Arc};
+
+/// Lifecycle of the transactions sent through lite-rpc by signature.
+///
+/// Transactions not received by lite-rpc are ignored, a lifecycle is kept for a blockhash
+/// validity window after the transaction expired so its expiry can still be queried.
+#[derive(Clone, Default)]
+pub struct TxLifecycleStore {
+    lifecycles: Arc<DashMap<String, TransactionLifecycle>>,
+}
+
+impl TxLifecycleStore {
+    pub fn received(&self, signature: String, last_valid_blockheight: u64, max_replay: usize) {
+        // the first reception is kept for the transactions sent several times
+        self.lifecycles
+            .entry(signature)
+            .or_insert_with(|| TransactionLifecycle {
+                received_at: Utc::now(),
+                last_valid_blockheight,
+                max_replay,
+                forwards: vec![],
+                replay_count: 0,
+                landed_slot: None,
+                confirmation_status: None,
+                expiry_reason: None,
+            });
+    }
+
+    /// Forward the transaction with `send`, given the index of the forward in the lifecycle so its
+    /// quic outcomes are reported on it, none if the transaction was not received by lite-rpc.
+    /// The forward is recorded when `send` succeeds, the lifecycle stays locked meanwhile so no
+    /// outcome can be reported before.
+    pub fn forward<E>(
+        &self,
+        signature: &str,
+        slot: Slot,
+        leaders: Vec<Pubkey>,
+        is_replay: bool,
+        send: impl FnOnce(Option<usize>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let Some(mut lifecycle) = self.lifecycles.get_mut(signature) else {
+            return send(None);
+        };
+        send(Some(lifecycle.forwards.len()))?;
+        lifecycle.forwards.push(TransactionForward {
+            slot,
+            forwarded_at: Utc::now(),
+            leaders,
+            quic_outcomes: HashMap::new(),
+        });
+        if is_replay {
+            lifecycle.replay_count += 1;
+        }
+        Ok(())
+    }
+
+    /// outcome of the forward to the leader
+    pub fn quic_outcome(
+        &self,
+        signature: &str,
+        forward_index: usize,
+        leader: Pubkey,
+        outcome: QuicSendOutcome,
+    ) {
+        let Some(mut lifecycle) = self.lifecycles.get_mut(signature) else {
+            return;
+        };
+        if let Some(forward) = lifecycle.forwards.get_mut(forward_index) {
+            forward.quic_outcomes.insert(leader, outcome);
+        }
+    }
+
+    pub fn landed(
+        &self,
+        signature: &str,
+        slot: Slot,
+        confirmation_status: TransactionConfirmationStatus,
+    ) {
+        let Some(mut lifecycle) = self.lifecycles.get_mut(signature) else {
+            return;
+        };
+        lifecycle.landed_slot = Some(slot);
+        lifecycle.confirmation_status = Some(confirmation_status);
+    }
+
+    pub fn expired(&self, signature: &str) {
+        let Some(mut lifecycle) = self.lifecycles.get_mut(signature) else {
+            return;
+        };
+        lifecycle.expiry_reason = Some(if lifecycle.replay_count >= lifecycle.max_replay {
+            TransactionExpiryReason::MaxReplaysReached
+        } else {
+            TransactionExpiryReason::BlockhashExpired
+        });
+    }
+
+    /// slot of the first forward to the tpu, none if the transaction hasn't been forwarded yet
+    pub fn first_forward_slot(&self, signature: &str) -> Option<Slot> {
+        self.lifecycles
+            .get(signature)
+            .and_then(|lifecycle| lifecycle.forwards.first().map(|forward| forward.slot))
+    }
+
+    pub fn get(&self, signature: &str) -> Option<TransactionLifecycle> {
+        self.lifecycles
+            .get(signature)
+            .map(|lifecycle| lifecycle.clone())
+    }
+
+    pub fn clean(&self, current_finalized_blockheight: u64) {
+        self.lifecycles.retain(|_, lifecycle| {
+            lifecycle.last_valid_blockheight + MAX_RECENT_BLOCKHASHES as u64
+                >= current_finalized_blockheight
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifecycle_records_forwards_and_expiry() {
+        let store = TxLifecycleStore::default();
+        let leader = Pubkey::new_unique();
+
+        let forward = |slot, is_replay| {
+            let mut forward_index = None;
+            store
+                .forward("sig", slot, vec![leader], is_replay, |index| {
+                    forward_index = index;
+                    Ok::<(), ()>(())
+                })
+                .unwrap();
+            forward_index
+        };
+
+        // not received by lite-rpc
+        assert_eq!(forward(10, false), None);
+        assert!(store.get("sig").is_none());
+
+        store.received("sig".to_string(), 100, 1);
+        assert_eq!(forward(10, false), Some(0));
+        assert_eq!(forward(12, true), Some(1));
+        // outcomes reported after the next forward
+        store.quic_outcome("sig", 1, leader, QuicSendOutcome::Sent);
+        store.quic_outcome("sig", 0, leader, QuicSendOutcome::Failed);
+        // failed sends are not recorded
+        assert!(store
+            .forward("sig", 14, vec![leader], true, |_| Err(()))
+            .is_err());
+        store.expired("sig");
+
+        let lifecycle = store.get("sig").unwrap();
+        assert_eq!(lifecycle.forwards.len(), 2);
+        assert_eq!(lifecycle.replay_count, 1);
+        assert_eq!(
+            lifecycle.forwards[0].quic_outcomes[&leader],
+            QuicSendOutcome::Failed
+        );
+        assert_eq!(
+            lifecycle.forwards[1].quic_outcomes[&leader],
+            QuicSendOutcome::Sent
+        );
+        assert_eq!(
+            lifecycle.expiry_reason,
+            Some(TransactionExpiryReason::MaxReplaysReached)
+        );
+
+        store.clean(100 + MAX_RECENT_BLOCKHASHES as u64);
+        assert!(store.get("sig").is_some());
+        store.clean(101 + MAX_RECENT_BLOCKHASHES as u64);
+        assert!(store.get("sig").is_none());
+    }
+}
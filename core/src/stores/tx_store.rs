@@ -59,11 +59,20 @@ impl TxStore {
         self.store.get(signature).map(|x| x.value().clone())
     }
 
-    pub fn clean(&self, current_finalized_blockheight: u64) {
+    /// Remove the transactions whose blockhash expired, returns the signatures of the ones sent
+    /// by lite-rpc that never landed
+    pub fn clean(&self, current_finalized_blockheight: u64) -> Vec<String> {
         let length_before = self.store.len();
-        self.store
-            .retain(|_k, v| v.last_valid_blockheight >= current_finalized_blockheight);
+        let mut expired = vec![];
+        self.store.retain(|signature, props| {
+            let is_valid = props.last_valid_blockheight >= current_finalized_blockheight;
+            if !is_valid && props.sent_by_lite_rpc && props.status.is_none() {
+                expired.push(signature.clone());
+            }
+            is_valid
+        });
         log::info!("Cleaned {} transactions", length_before - self.store.len());
+        expired
     }
 
     pub fn is_transaction_confirmed(&self, signature: &String) -> bool {
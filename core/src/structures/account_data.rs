@@ -0,0 +1,40 @@
+use crate::commitment_utils::Commitment;
+use solana_rpc_client_api::filter::RpcFilterType;
+use solana_sdk::{account::Account, pubkey::Pubkey, slot_history::Slot};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+};
+
+#[derive(Debug, Clone)]
+pub struct AccountData {
+    pub pubkey: Pubkey,
+    pub account: Arc<Account>,
+    pub updated_slot: Slot,
+    pub write_version: u64,
+}
+
+/// account update notified by the cluster endpoint at a commitment
+#[derive(Debug, Clone)]
+pub struct AccountNotificationMessage {
+    pub data: AccountData,
+    pub commitment: Commitment,
+}
+
+/// accounts and programs whose updates are streamed at a commitment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountStreamFilter {
+    pub accounts: BTreeSet<Pubkey>,
+    /// owners of the accounts to stream with the filters the accounts must match,
+    /// all the accounts of the program are streamed when there is no filter
+    pub programs: BTreeMap<Pubkey, Vec<RpcFilterType>>,
+}
+
+impl AccountStreamFilter {
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.programs.is_empty()
+    }
+}
+
+/// filters of the account stream by commitment, no account update is streamed for a missing commitment
+pub type AccountStreamFilters = HashMap<Commitment, AccountStreamFilter>;
@@ -1,5 +1,6 @@
 use crate::stores::block_information_store::BlockInformation;
 use crate::stores::data_cache::DataCache;
+use serde::{Deserialize, Serialize};
 use solana_rpc_client_api::config::RpcGetVoteAccountsConfig;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::ParsePubkeyError;
@@ -35,7 +36,7 @@ impl TryFrom<RpcGetVoteAccountsConfig> for GetVoteAccountsConfig {
     }
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct CalculatedSchedule {
     pub current: Option<LeaderScheduleData>,
     pub next: Option<LeaderScheduleData>,
@@ -46,6 +47,7 @@ impl CalculatedSchedule {
         &self,
         slot: Option<u64>,
         commitment: Option<CommitmentConfig>,
+        identity: Option<&Pubkey>,
         data_cache: &DataCache,
     ) -> Option<HashMap<String, Vec<usize>>> {
         log::debug!(
@@ -66,13 +68,32 @@ impl CalculatedSchedule {
             }
         };
         let epoch = data_cache.epoch_data.get_epoch_at_slot(slot);
+        self.get_leader_schedule_for_epoch(epoch.epoch, identity)
+    }
 
-        let get_schedule = |schedule_data: Option<&LeaderScheduleData>| {
-            schedule_data.and_then(|current| {
-                (current.epoch == epoch.epoch).then_some(current.schedule_by_node.clone())
-            })
-        };
-        get_schedule(self.current.as_ref()).or_else(|| get_schedule(self.next.as_ref()))
+    // schedule by node of the epoch, only with the identity leader slots if set
+    pub fn get_leader_schedule_for_epoch(
+        &self,
+        epoch: u64,
+        identity: Option<&Pubkey>,
+    ) -> Option<HashMap<String, Vec<usize>>> {
+        let schedule_data = [self.current.as_ref(), self.next.as_ref()]
+            .into_iter()
+            .flatten()
+            .find(|schedule_data| schedule_data.epoch == epoch)?;
+        match identity {
+            Some(identity) => {
+                let identity = identity.to_string();
+                Some(
+                    schedule_data
+                        .schedule_by_node
+                        .get(&identity)
+                        .map(|slots| HashMap::from([(identity, slots.clone())]))
+                        .unwrap_or_default(),
+                )
+            }
+            None => Some(schedule_data.schedule_by_node.clone()),
+        }
     }
 
     pub async fn get_slot_leaders(
@@ -89,7 +110,7 @@ impl CalculatedSchedule {
         pub const MAX_GET_SLOT_LEADERS: usize =
             solana_rpc_client_api::request::MAX_GET_SLOT_LEADERS;
 
-        let mut limit = limit as usize;
+        let limit = limit as usize;
         if limit > MAX_GET_SLOT_LEADERS {
             return Err(format!(
                 "Invalid Params: Invalid limit; max {MAX_GET_SLOT_LEADERS}"
@@ -100,9 +121,9 @@ impl CalculatedSchedule {
         let mut slot_leaders = Vec::with_capacity(limit);
 
         let mut extend_slot_from_epoch = |leader_schedule: &[Pubkey], slot_index: usize| {
+            // at the epoch boundary the remaining leaders are taken from the next epoch
             let take = limit.saturating_sub(slot_leaders.len());
             slot_leaders.extend(leader_schedule.iter().skip(slot_index).take(take));
-            limit -= slot_leaders.len();
         };
 
         // log::info!(
@@ -137,9 +158,71 @@ impl CalculatedSchedule {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct LeaderScheduleData {
     pub schedule_by_node: HashMap<String, Vec<usize>>,
     pub schedule_by_slot: Vec<Pubkey>,
     pub epoch: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule_data(epoch: u64, leader: Pubkey, slots_in_epoch: usize) -> LeaderScheduleData {
+        LeaderScheduleData {
+            schedule_by_node: HashMap::from([(leader.to_string(), (0..slots_in_epoch).collect())]),
+            schedule_by_slot: vec![leader; slots_in_epoch],
+            epoch,
+        }
+    }
+
+    #[test]
+    fn slot_leaders_span_the_epoch_boundary() {
+        let epoch_schedule = EpochSchedule::custom(32, 32, false);
+        let (current_leader, next_leader) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let schedule = CalculatedSchedule {
+            current: Some(schedule_data(0, current_leader, 32)),
+            next: Some(schedule_data(1, next_leader, 32)),
+        };
+
+        let slot_leaders =
+            futures::executor::block_on(schedule.get_slot_leaders(30, 4, &epoch_schedule)).unwrap();
+        assert_eq!(
+            slot_leaders,
+            vec![current_leader, current_leader, next_leader, next_leader]
+        );
+
+        let slot_leaders =
+            futures::executor::block_on(schedule.get_slot_leaders(34, 2, &epoch_schedule)).unwrap();
+        assert_eq!(slot_leaders, vec![next_leader, next_leader]);
+
+        assert!(
+            futures::executor::block_on(schedule.get_slot_leaders(70, 2, &epoch_schedule)).is_err()
+        );
+    }
+
+    #[test]
+    fn leader_schedule_filtered_by_identity() {
+        let (current_leader, next_leader) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let schedule = CalculatedSchedule {
+            current: Some(schedule_data(0, current_leader, 32)),
+            next: Some(schedule_data(1, next_leader, 32)),
+        };
+
+        let next_schedule = schedule.get_leader_schedule_for_epoch(1, None).unwrap();
+        assert_eq!(next_schedule.len(), 1);
+        assert_eq!(next_schedule[&next_leader.to_string()].len(), 32);
+
+        let current_schedule = schedule
+            .get_leader_schedule_for_epoch(0, Some(&current_leader))
+            .unwrap();
+        assert_eq!(current_schedule[&current_leader.to_string()].len(), 32);
+        assert!(schedule
+            .get_leader_schedule_for_epoch(0, Some(&next_leader))
+            .unwrap()
+            .is_empty());
+
+        assert!(schedule.get_leader_schedule_for_epoch(2, None).is_none());
+    }
+}
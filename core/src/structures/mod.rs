@@ -1,5 +1,6 @@
 // this mod will contain all the core structures that are defined for lite-rpc
 
+pub mod account_data;
 pub mod epoch;
 pub mod identity_stakes;
 pub mod leader_data;
@@ -9,4 +10,5 @@ pub mod produced_block;
 pub mod proxy_request_format;
 pub mod rotating_queue;
 pub mod slot_notification;
+pub mod transaction_lifecycle;
 pub mod transaction_sent_info;
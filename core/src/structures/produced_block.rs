@@ -1,16 +1,30 @@
+use solana_rpc_client_api::{
+    config::{RpcBlockConfig, RpcTransactionConfig},
+    response::RpcConfirmedTransactionStatusWithSignature,
+};
 use solana_sdk::{
     borsh0_10::try_from_slice_unchecked,
     commitment_config::CommitmentConfig,
     compute_budget::{self, ComputeBudgetInstruction},
+    instruction::CompiledInstruction,
+    message::{v0::LoadedAddresses, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
     slot_history::Slot,
-    transaction::TransactionError,
+    transaction::{TransactionError, VersionedTransaction},
+    transaction_context::TransactionReturnData,
 };
 use solana_transaction_status::{
-    option_serializer::OptionSerializer, Reward, RewardType, UiConfirmedBlock,
-    UiTransactionStatusMeta,
+    extract_memos::extract_and_fmt_memos, option_serializer::OptionSerializer,
+    BlockEncodingOptions, ConfirmedBlock, EncodedConfirmedTransactionWithStatusMeta,
+    EncodedTransactionWithStatusMeta, InnerInstruction, InnerInstructions, Reward, RewardType,
+    TransactionConfirmationStatus, TransactionStatusMeta, TransactionTokenBalance,
+    TransactionWithStatusMeta, UiConfirmedBlock, UiInstruction, UiTransactionEncoding,
+    UiTransactionStatusMeta, UiTransactionTokenBalance, VersionedTransactionWithStatusMeta,
 };
 
-use crate::encoding::BinaryEncoding;
+use crate::{commitment_utils::Commitment, encoding::BinaryEncoding};
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub struct TransactionInfo {
@@ -21,6 +35,220 @@ pub struct TransactionInfo {
     pub cu_consumed: Option<u64>,
     pub recent_blockhash: String,
     pub message: String,
+    pub signatures: Vec<String>,
+    /// full status meta of the transaction, only kept when enabled in the configuration
+    pub meta: Option<TransactionStatusMeta>,
+}
+
+impl TransactionInfo {
+    /// decode a transaction received from the rpc, the transaction has to be binary encoded
+    pub fn from_encoded_transaction(
+        tx: EncodedTransactionWithStatusMeta,
+        keep_transaction_meta: bool,
+    ) -> Option<Self> {
+        let Some(ui_meta) = tx.meta else {
+            // ignoring transaction
+            log::info!("Tx with no meta");
+            return None;
+        };
+
+        let Some(tx) = tx.transaction.decode() else {
+            // ignoring transaction
+            log::info!("Tx could not be decoded");
+            return None;
+        };
+
+        let signature = tx.signatures[0].to_string();
+        let err = ui_meta.err.clone();
+        let cu_consumed = match ui_meta.compute_units_consumed {
+            OptionSerializer::Some(cu_consumed) => Some(cu_consumed),
+            _ => None,
+        };
+        let meta = if keep_transaction_meta {
+            match transaction_status_meta_from_ui(ui_meta) {
+                Ok(meta) => Some(meta),
+                Err(e) => {
+                    log::warn!("Could not decode meta of tx {signature}: {e:?}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let legacy_compute_budget = tx.message.instructions().iter().find_map(|i| {
+            if i.program_id(tx.message.static_account_keys())
+                .eq(&compute_budget::id())
+            {
+                if let Ok(ComputeBudgetInstruction::RequestUnitsDeprecated {
+                    units,
+                    additional_fee,
+                }) = try_from_slice_unchecked(i.data.as_slice())
+                {
+                    return Some((units, additional_fee));
+                }
+            }
+            None
+        });
+
+        let mut cu_requested = tx.message.instructions().iter().find_map(|i| {
+            if i.program_id(tx.message.static_account_keys())
+                .eq(&compute_budget::id())
+            {
+                if let Ok(ComputeBudgetInstruction::SetComputeUnitLimit(limit)) =
+                    try_from_slice_unchecked(i.data.as_slice())
+                {
+                    return Some(limit);
+                }
+            }
+            None
+        });
+
+        let mut prioritization_fees = tx.message.instructions().iter().find_map(|i| {
+            if i.program_id(tx.message.static_account_keys())
+                .eq(&compute_budget::id())
+            {
+                if let Ok(ComputeBudgetInstruction::SetComputeUnitPrice(price)) =
+                    try_from_slice_unchecked(i.data.as_slice())
+                {
+                    return Some(price);
+                }
+            }
+
+            None
+        });
+
+        if let Some((units, additional_fee)) = legacy_compute_budget {
+            cu_requested = Some(units);
+            if additional_fee > 0 {
+                prioritization_fees = Some(calc_prioritization_fees(units, additional_fee))
+            }
+        };
+
+        let blockhash = tx.message.recent_blockhash().to_string();
+        let message = BinaryEncoding::Base64.encode(tx.message.serialize());
+
+        Some(TransactionInfo {
+            signature,
+            err,
+            cu_requested,
+            prioritization_fees,
+            cu_consumed,
+            recent_blockhash: blockhash,
+            message,
+            signatures: tx.signatures.iter().map(|s| s.to_string()).collect(),
+            meta,
+        })
+    }
+
+    /// decode the stored base64 message
+    pub fn versioned_message(&self) -> anyhow::Result<VersionedMessage> {
+        Ok(bincode::deserialize(
+            &BinaryEncoding::Base64.decode(&self.message)?,
+        )?)
+    }
+
+    /// rebuild the versioned transaction and its status meta from the stored message
+    pub fn to_transaction_with_status_meta(&self) -> anyhow::Result<TransactionWithStatusMeta> {
+        let message = self.versioned_message()?;
+
+        let signatures = if self.signatures.is_empty() {
+            // only the first signature is known, the other required signatures are left empty
+            let num_required_signatures = message.header().num_required_signatures as usize;
+            let mut signatures = vec![Signature::default(); num_required_signatures.max(1)];
+            signatures[0] = Signature::from_str(&self.signature)?;
+            signatures
+        } else {
+            self.signatures
+                .iter()
+                .map(|signature| Signature::from_str(signature))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        let meta = match &self.meta {
+            Some(meta) => meta.clone(),
+            None => TransactionStatusMeta {
+                status: self.err.clone().map_or(Ok(()), Err),
+                compute_units_consumed: self.cu_consumed,
+                ..Default::default()
+            },
+        };
+
+        Ok(TransactionWithStatusMeta::Complete(
+            VersionedTransactionWithStatusMeta {
+                transaction: VersionedTransaction {
+                    signatures,
+                    message,
+                },
+                meta,
+            },
+        ))
+    }
+
+    /// static account keys of the message followed by the addresses loaded from lookup tables,
+    /// loaded addresses are only known when the transaction meta is kept
+    pub fn account_keys(&self) -> anyhow::Result<Vec<Pubkey>> {
+        let mut account_keys = self.versioned_message()?.static_account_keys().to_vec();
+        if let Some(meta) = &self.meta {
+            account_keys.extend_from_slice(&meta.loaded_addresses.writable);
+            account_keys.extend_from_slice(&meta.loaded_addresses.readonly);
+        }
+        Ok(account_keys)
+    }
+
+    /// same as `account_keys` keeping only the accounts write locked by the transaction
+    pub fn writable_account_keys(&self) -> anyhow::Result<Vec<Pubkey>> {
+        let message = self.versioned_message()?;
+        let mut account_keys: Vec<Pubkey> = message
+            .static_account_keys()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| message.is_maybe_writable(*index))
+            .map(|(_, account)| *account)
+            .collect();
+        if let Some(meta) = &self.meta {
+            account_keys.extend_from_slice(&meta.loaded_addresses.writable);
+        }
+        Ok(account_keys)
+    }
+
+    pub fn is_vote(&self) -> bool {
+        self.versioned_message().is_ok_and(|message| {
+            message.instructions().iter().any(|instruction| {
+                instruction
+                    .program_id(message.static_account_keys())
+                    .eq(&solana_sdk::vote::program::id())
+            })
+        })
+    }
+
+    pub fn memo(&self) -> Option<String> {
+        match self.to_transaction_with_status_meta() {
+            Ok(TransactionWithStatusMeta::Complete(tx)) => extract_and_fmt_memos(&tx),
+            _ => None,
+        }
+    }
+
+    pub fn to_signature_status(
+        &self,
+        slot: Slot,
+        block_time: u64,
+        commitment_config: CommitmentConfig,
+    ) -> RpcConfirmedTransactionStatusWithSignature {
+        let confirmation_status = match Commitment::from(commitment_config) {
+            Commitment::Finalized => TransactionConfirmationStatus::Finalized,
+            Commitment::Confirmed => TransactionConfirmationStatus::Confirmed,
+            Commitment::Processed => TransactionConfirmationStatus::Processed,
+        };
+        RpcConfirmedTransactionStatusWithSignature {
+            signature: self.signature.clone(),
+            slot,
+            err: self.err.clone(),
+            memo: self.memo(),
+            block_time: (block_time != 0).then_some(block_time as i64),
+            confirmation_status: Some(confirmation_status),
+        }
+    }
 }
 
 #[derive(Default, Debug, Clone)]
@@ -42,6 +270,7 @@ impl ProducedBlock {
         block: UiConfirmedBlock,
         slot: Slot,
         commitment_config: CommitmentConfig,
+        keep_transaction_meta: bool,
     ) -> Self {
         let block_height = block.block_height.unwrap_or_default();
         let txs = block.transactions.unwrap_or_default();
@@ -53,92 +282,7 @@ impl ProducedBlock {
 
         let txs = txs
             .into_iter()
-            .filter_map(|tx| {
-                let Some(UiTransactionStatusMeta {
-                    err,
-                    compute_units_consumed,
-                    ..
-                }) = tx.meta
-                else {
-                    // ignoring transaction
-                    log::info!("Tx with no meta");
-                    return None;
-                };
-
-                let Some(tx) = tx.transaction.decode() else {
-                    // ignoring transaction
-                    log::info!("Tx could not be decoded");
-                    return None;
-                };
-
-                let signature = tx.signatures[0].to_string();
-                let cu_consumed = match compute_units_consumed {
-                    OptionSerializer::Some(cu_consumed) => Some(cu_consumed),
-                    _ => None,
-                };
-
-                let legacy_compute_budget = tx.message.instructions().iter().find_map(|i| {
-                    if i.program_id(tx.message.static_account_keys())
-                        .eq(&compute_budget::id())
-                    {
-                        if let Ok(ComputeBudgetInstruction::RequestUnitsDeprecated {
-                            units,
-                            additional_fee,
-                        }) = try_from_slice_unchecked(i.data.as_slice())
-                        {
-                            return Some((units, additional_fee));
-                        }
-                    }
-                    None
-                });
-
-                let mut cu_requested = tx.message.instructions().iter().find_map(|i| {
-                    if i.program_id(tx.message.static_account_keys())
-                        .eq(&compute_budget::id())
-                    {
-                        if let Ok(ComputeBudgetInstruction::SetComputeUnitLimit(limit)) =
-                            try_from_slice_unchecked(i.data.as_slice())
-                        {
-                            return Some(limit);
-                        }
-                    }
-                    None
-                });
-
-                let mut prioritization_fees = tx.message.instructions().iter().find_map(|i| {
-                    if i.program_id(tx.message.static_account_keys())
-                        .eq(&compute_budget::id())
-                    {
-                        if let Ok(ComputeBudgetInstruction::SetComputeUnitPrice(price)) =
-                            try_from_slice_unchecked(i.data.as_slice())
-                        {
-                            return Some(price);
-                        }
-                    }
-
-                    None
-                });
-
-                if let Some((units, additional_fee)) = legacy_compute_budget {
-                    cu_requested = Some(units);
-                    if additional_fee > 0 {
-                        prioritization_fees = Some(calc_prioritization_fees(units, additional_fee))
-                    }
-                };
-
-                let blockhash = tx.message.recent_blockhash().to_string();
-                let message = BinaryEncoding::Base64.encode(tx.message.serialize());
-
-                Some(TransactionInfo {
-                    signature,
-                    err,
-                    cu_requested,
-                    prioritization_fees,
-                    cu_consumed,
-                    recent_blockhash: blockhash,
-                    message,
-                })
-            })
+            .filter_map(|tx| TransactionInfo::from_encoded_transaction(tx, keep_transaction_meta))
             .collect();
 
         let leader_id = if let Some(rewards) = block.rewards {
@@ -166,6 +310,61 @@ impl ProducedBlock {
         }
     }
 
+    /// convert the block back into the rpc representation, honouring the requested encoding,
+    /// transaction details, rewards and max supported transaction version
+    pub fn to_ui_block(&self, config: &RpcBlockConfig) -> anyhow::Result<UiConfirmedBlock> {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(|tx| tx.to_transaction_with_status_meta())
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let block = ConfirmedBlock {
+            previous_blockhash: self.previous_blockhash.clone(),
+            blockhash: self.blockhash.clone(),
+            parent_slot: self.parent_slot,
+            transactions,
+            rewards: self.rewards.clone().unwrap_or_default(),
+            block_time: (self.block_time != 0).then_some(self.block_time as i64),
+            block_height: Some(self.block_height),
+        };
+
+        let encoding = config.encoding.unwrap_or(UiTransactionEncoding::Json);
+        let options = BlockEncodingOptions {
+            transaction_details: config.transaction_details.unwrap_or_default(),
+            show_rewards: config.rewards.unwrap_or(true),
+            max_supported_transaction_version: config.max_supported_transaction_version,
+        };
+
+        Ok(block.encode_with_options(encoding, options)?)
+    }
+
+    /// copy of the block keeping only the transactions mentioning the account or program,
+    /// none if no transaction of the block mentions it
+    pub fn with_transactions_mentioning(&self, account: &Pubkey) -> Option<Self> {
+        let transactions: Vec<TransactionInfo> = self
+            .transactions
+            .iter()
+            .filter(|tx| tx.account_keys().is_ok_and(|keys| keys.contains(account)))
+            .cloned()
+            .collect();
+        if transactions.is_empty() {
+            return None;
+        }
+        Some(ProducedBlock {
+            transactions,
+            leader_id: self.leader_id.clone(),
+            blockhash: self.blockhash.clone(),
+            block_height: self.block_height,
+            slot: self.slot,
+            parent_slot: self.parent_slot,
+            block_time: self.block_time,
+            commitment_config: self.commitment_config,
+            previous_blockhash: self.previous_blockhash.clone(),
+            rewards: self.rewards.clone(),
+        })
+    }
+
     /// moving commitment level to finalized
     pub fn to_finalized_block(&self) -> Self {
         ProducedBlock {
@@ -175,6 +374,127 @@ impl ProducedBlock {
     }
 }
 
+/// a transaction with the information of the block it was included in
+#[derive(Debug, Clone)]
+pub struct ProducedTransaction {
+    pub transaction: TransactionInfo,
+    pub slot: Slot,
+    pub block_time: u64,
+    pub commitment_config: CommitmentConfig,
+}
+
+impl ProducedTransaction {
+    pub fn to_encoded_transaction(
+        &self,
+        config: &RpcTransactionConfig,
+    ) -> anyhow::Result<EncodedConfirmedTransactionWithStatusMeta> {
+        let encoding = config.encoding.unwrap_or(UiTransactionEncoding::Json);
+        let transaction = self.transaction.to_transaction_with_status_meta()?.encode(
+            encoding,
+            config.max_supported_transaction_version,
+            true,
+        )?;
+
+        Ok(EncodedConfirmedTransactionWithStatusMeta {
+            slot: self.slot,
+            transaction,
+            block_time: (self.block_time != 0).then_some(self.block_time as i64),
+        })
+    }
+}
+
+/// convert the meta received from the rpc back into the internal representation,
+/// inner instructions have to be in the compiled format (binary transaction encoding)
+pub fn transaction_status_meta_from_ui(
+    meta: UiTransactionStatusMeta,
+) -> anyhow::Result<TransactionStatusMeta> {
+    let inner_instructions = match meta.inner_instructions {
+        OptionSerializer::Some(inner_instructions) => Some(
+            inner_instructions
+                .into_iter()
+                .map(|inner| {
+                    let instructions = inner
+                        .instructions
+                        .into_iter()
+                        .map(|instruction| match instruction {
+                            UiInstruction::Compiled(compiled) => Ok(InnerInstruction {
+                                instruction: CompiledInstruction {
+                                    program_id_index: compiled.program_id_index,
+                                    accounts: compiled.accounts,
+                                    data: BinaryEncoding::Base58.decode(compiled.data)?,
+                                },
+                                stack_height: compiled.stack_height,
+                            }),
+                            UiInstruction::Parsed(_) => {
+                                anyhow::bail!("parsed inner instructions are not supported")
+                            }
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    Ok(InnerInstructions {
+                        index: inner.index,
+                        instructions,
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        ),
+        _ => None,
+    };
+
+    let token_balances = |balances: OptionSerializer<Vec<UiTransactionTokenBalance>>| {
+        Option::<Vec<_>>::from(balances).map(|balances| {
+            balances
+                .into_iter()
+                .map(|balance| TransactionTokenBalance {
+                    account_index: balance.account_index,
+                    mint: balance.mint,
+                    ui_token_amount: balance.ui_token_amount,
+                    owner: Option::from(balance.owner).unwrap_or_default(),
+                    program_id: Option::from(balance.program_id).unwrap_or_default(),
+                })
+                .collect()
+        })
+    };
+
+    let loaded_addresses = match meta.loaded_addresses {
+        OptionSerializer::Some(loaded_addresses) => LoadedAddresses {
+            writable: loaded_addresses
+                .writable
+                .iter()
+                .map(|key| Pubkey::from_str(key))
+                .collect::<Result<_, _>>()?,
+            readonly: loaded_addresses
+                .readonly
+                .iter()
+                .map(|key| Pubkey::from_str(key))
+                .collect::<Result<_, _>>()?,
+        },
+        _ => LoadedAddresses::default(),
+    };
+
+    let return_data = match meta.return_data {
+        OptionSerializer::Some(return_data) => Some(TransactionReturnData {
+            program_id: Pubkey::from_str(&return_data.program_id)?,
+            data: BinaryEncoding::Base64.decode(return_data.data.0)?,
+        }),
+        _ => None,
+    };
+
+    Ok(TransactionStatusMeta {
+        status: meta.status,
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions,
+        log_messages: meta.log_messages.into(),
+        pre_token_balances: token_balances(meta.pre_token_balances),
+        post_token_balances: token_balances(meta.post_token_balances),
+        rewards: meta.rewards.into(),
+        loaded_addresses,
+        return_data,
+        compute_units_consumed: meta.compute_units_consumed.into(),
+    })
+}
+
 #[inline]
 fn calc_prioritization_fees(units: u32, additional_fee: u32) -> u64 {
     (units as u64 * 1000) / additional_fee as u64
@@ -189,3 +509,157 @@ fn overflow_u32() {
 
     assert_eq!(40_000_000_000, prioritization_fees);
 }
+
+#[test]
+fn to_ui_block_with_signatures() {
+    use solana_sdk::{
+        hash::Hash, message::Message, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_instruction, transaction::Transaction,
+    };
+    use solana_transaction_status::TransactionDetails;
+
+    let payer = Keypair::new();
+    let instruction = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let tx = Transaction::new(&[&payer], message, Hash::default());
+
+    let block = ProducedBlock {
+        transactions: vec![TransactionInfo {
+            signature: tx.signatures[0].to_string(),
+            err: None,
+            cu_requested: None,
+            prioritization_fees: None,
+            cu_consumed: Some(150),
+            recent_blockhash: tx.message.recent_blockhash.to_string(),
+            message: BinaryEncoding::Base64
+                .encode(VersionedMessage::Legacy(tx.message.clone()).serialize()),
+            signatures: vec![],
+            meta: None,
+        }],
+        slot: 42,
+        block_height: 40,
+        ..Default::default()
+    };
+
+    let config = RpcBlockConfig {
+        transaction_details: Some(TransactionDetails::Signatures),
+        ..Default::default()
+    };
+    let ui_block = block.to_ui_block(&config).unwrap();
+    assert_eq!(
+        ui_block.signatures,
+        Some(vec![tx.signatures[0].to_string()])
+    );
+    assert!(ui_block.transactions.is_none());
+
+    let config = RpcBlockConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        ..Default::default()
+    };
+    let ui_block = block.to_ui_block(&config).unwrap();
+    let transactions = ui_block.transactions.unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(
+        transactions[0].transaction.decode().unwrap().signatures,
+        tx.signatures
+    );
+}
+
+#[test]
+fn transaction_status_meta_roundtrip() {
+    use solana_account_decoder::parse_token::UiTokenAmount;
+
+    let meta = TransactionStatusMeta {
+        status: Ok(()),
+        fee: 5000,
+        pre_balances: vec![10_000, 0],
+        post_balances: vec![4_000, 1_000],
+        inner_instructions: Some(vec![InnerInstructions {
+            index: 0,
+            instructions: vec![InnerInstruction {
+                instruction: CompiledInstruction::new_from_raw_parts(1, vec![1, 2, 3], vec![0]),
+                stack_height: Some(2),
+            }],
+        }]),
+        log_messages: Some(vec!["Program log: hello".to_string()]),
+        pre_token_balances: Some(vec![TransactionTokenBalance {
+            account_index: 1,
+            mint: Pubkey::new_unique().to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: Some(1.0),
+                decimals: 6,
+                amount: "1000000".to_string(),
+                ui_amount_string: "1".to_string(),
+            },
+            owner: Pubkey::new_unique().to_string(),
+            program_id: Pubkey::new_unique().to_string(),
+        }]),
+        post_token_balances: Some(vec![]),
+        rewards: Some(vec![]),
+        loaded_addresses: LoadedAddresses {
+            writable: vec![Pubkey::new_unique()],
+            readonly: vec![Pubkey::new_unique()],
+        },
+        return_data: Some(TransactionReturnData {
+            program_id: Pubkey::new_unique(),
+            data: vec![4, 5, 6],
+        }),
+        compute_units_consumed: Some(1234),
+    };
+
+    let ui_meta = UiTransactionStatusMeta::from(meta.clone());
+    assert_eq!(transaction_status_meta_from_ui(ui_meta).unwrap(), meta);
+}
+
+#[test]
+fn block_with_transactions_mentioning() {
+    use solana_sdk::{
+        hash::Hash, message::Message, signature::Keypair, signer::Signer, system_instruction,
+        system_program, transaction::Transaction,
+    };
+
+    let to_transaction_info = |recipient: &Pubkey| {
+        let payer = Keypair::new();
+        let instruction = system_instruction::transfer(&payer.pubkey(), recipient, 1);
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let tx = Transaction::new(&[&payer], message, Hash::default());
+        TransactionInfo {
+            signature: tx.signatures[0].to_string(),
+            err: None,
+            cu_requested: None,
+            prioritization_fees: None,
+            cu_consumed: None,
+            recent_blockhash: tx.message.recent_blockhash.to_string(),
+            message: BinaryEncoding::Base64
+                .encode(VersionedMessage::Legacy(tx.message.clone()).serialize()),
+            signatures: vec![],
+            meta: None,
+        }
+    };
+    let (recipient, other_recipient) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let block = ProducedBlock {
+        transactions: vec![
+            to_transaction_info(&recipient),
+            to_transaction_info(&other_recipient),
+        ],
+        slot: 42,
+        ..Default::default()
+    };
+
+    let filtered = block.with_transactions_mentioning(&recipient).unwrap();
+    assert_eq!(filtered.slot, 42);
+    assert_eq!(filtered.transactions.len(), 1);
+    assert_eq!(
+        filtered.transactions[0].signature,
+        block.transactions[0].signature
+    );
+
+    let filtered = block
+        .with_transactions_mentioning(&system_program::id())
+        .unwrap();
+    assert_eq!(filtered.transactions.len(), 2);
+
+    assert!(block
+        .with_transactions_mentioning(&Pubkey::new_unique())
+        .is_none());
+}
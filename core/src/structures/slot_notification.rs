@@ -8,4 +8,6 @@ pub type AtomicSlot = Arc<AtomicU64>;
 pub struct SlotNotification {
     pub processed_slot: Slot,
     pub estimated_processed_slot: Slot,
+    /// parent of the processed slot, only known when the slots are notified by geyser
+    pub parent_slot: Option<Slot>,
 }
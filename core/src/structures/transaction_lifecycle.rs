@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, slot_history::Slot};
+use solana_transaction_status::TransactionConfirmationStatus;
+use std::collections::HashMap;
+
+/// State of a transaction sent through lite-rpc
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionLifecycleStatus {
+    /// sent to the tpu of the upcoming leaders
+    Forwarded,
+    /// sent again as it has not landed yet
+    Replayed,
+    Processed,
+    Confirmed,
+    Finalized,
+    /// its blockhash expired before it landed
+    Expired,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransactionLifecycleEvent {
+    pub signature: String,
+    pub status: TransactionLifecycleStatus,
+    /// slot the transaction was forwarded or landed at, the finalized slot when it expired
+    pub slot: Slot,
+}
+
+/// Outcome of writing a transaction to the quic connection of a leader
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuicSendOutcome {
+    Sent,
+    Failed,
+}
+
+/// Why a transaction sent through lite-rpc never landed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionExpiryReason {
+    /// its blockhash expired while it was still replayed
+    BlockhashExpired,
+    /// its blockhash expired after the last replay
+    MaxReplaysReached,
+}
+
+/// A forward of the transaction to the tpu of the upcoming leaders
+#[derive(Debug, Clone)]
+pub struct TransactionForward {
+    pub slot: Slot,
+    pub forwarded_at: DateTime<Utc>,
+    pub leaders: Vec<Pubkey>,
+    /// set once the transaction is written to the connection of a leader
+    pub quic_outcomes: HashMap<Pubkey, QuicSendOutcome>,
+}
+
+/// Everything lite-rpc did with a transaction sent through it
+#[derive(Debug, Clone)]
+pub struct TransactionLifecycle {
+    pub received_at: DateTime<Utc>,
+    pub last_valid_blockheight: u64,
+    pub max_replay: usize,
+    /// the first forward followed by the replays
+    pub forwards: Vec<TransactionForward>,
+    pub replay_count: usize,
+    pub landed_slot: Option<Slot>,
+    pub confirmation_status: Option<TransactionConfirmationStatus>,
+    pub expiry_reason: Option<TransactionExpiryReason>,
+}
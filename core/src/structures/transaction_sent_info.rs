@@ -8,4 +8,6 @@ pub struct SentTransactionInfo {
     pub slot: Slot,
     pub transaction: WireTransaction,
     pub last_valid_block_height: u64,
+    /// index of the forward in the transaction lifecycle, set when sent to the tpu
+    pub forward_index: Option<usize>,
 }
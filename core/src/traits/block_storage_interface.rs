@@ -1,8 +1,11 @@
-use crate::structures::produced_block::ProducedBlock;
+use crate::structures::produced_block::{ProducedBlock, ProducedTransaction};
 use anyhow::Result;
 use async_trait::async_trait;
-use solana_rpc_client_api::config::RpcBlockConfig;
-use solana_sdk::slot_history::Slot;
+use solana_rpc_client_api::{
+    config::{RpcBlockConfig, RpcSignaturesForAddressConfig, RpcTransactionConfig},
+    response::RpcConfirmedTransactionStatusWithSignature,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot};
 use std::{ops::Range, sync::Arc};
 
 #[async_trait]
@@ -13,7 +16,26 @@ pub trait BlockStorageInterface: Send + Sync {
     async fn get(&self, slot: Slot, config: RpcBlockConfig) -> Result<ProducedBlock>;
     // will get range of slots that are stored in the storage
     async fn get_slot_range(&self) -> Range<Slot>;
+    // will get the slots of the stored blocks within the range, skipped slots are not listed
+    async fn get_slots_in_range(
+        &self,
+        range: Range<Slot>,
+        commitment_config: CommitmentConfig,
+    ) -> Result<Vec<Slot>>;
+    // will get a transaction with the slot of the block containing it
+    async fn get_transaction(
+        &self,
+        signature: &str,
+        config: RpcTransactionConfig,
+    ) -> Result<ProducedTransaction>;
+    // will get the signatures of the transactions referencing the account, newest first
+    async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        config: RpcSignaturesForAddressConfig,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>>;
 }
 
 pub type BlockStorageImpl = Arc<dyn BlockStorageInterface>;
 pub const BLOCK_NOT_FOUND: &str = "Block not found";
+pub const TRANSACTION_NOT_FOUND: &str = "Transaction not found";
@@ -1,10 +1,15 @@
 use std::sync::Arc;
 
 use solana_rpc_client_api::response::{RpcContactInfo, RpcVoteAccountStatus};
-use tokio::sync::broadcast::Receiver;
+use tokio::sync::{broadcast::Receiver, mpsc, oneshot, watch};
 
 use crate::{
-    structures::{produced_block::ProducedBlock, slot_notification::SlotNotification},
+    structures::{
+        account_data::{AccountNotificationMessage, AccountStreamFilters},
+        leaderschedule::GetVoteAccountsConfig,
+        produced_block::ProducedBlock,
+        slot_notification::SlotNotification,
+    },
     traits::subscription_sink::SubscriptionSink,
 };
 
@@ -12,4 +17,16 @@ pub type BlockStream = Receiver<ProducedBlock>;
 pub type SlotStream = Receiver<SlotNotification>;
 pub type VoteAccountStream = Receiver<RpcVoteAccountStatus>;
 pub type ClusterInfoStream = Receiver<Vec<RpcContactInfo>>;
+pub type AccountStream = Receiver<AccountNotificationMessage>;
 pub type SubscptionHanderSink = Arc<dyn SubscriptionSink>;
+
+// getVoteAccounts requests answered by the stake and vote loop, with None while the stakes of the
+// epoch are not computed
+pub type VoteAccountsRequestSender = mpsc::Sender<(
+    GetVoteAccountsConfig,
+    oneshot::Sender<Option<RpcVoteAccountStatus>>,
+)>;
+
+// accounts and programs streamed by the cluster endpoint, changed with the active subscriptions
+pub type AccountFiltersSender = watch::Sender<AccountStreamFilters>;
+pub type AccountFiltersReceiver = watch::Receiver<AccountStreamFilters>;
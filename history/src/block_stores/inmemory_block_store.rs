@@ -1,16 +1,30 @@
 use async_trait::async_trait;
+use dashmap::DashMap;
 use solana_lite_rpc_core::{
     commitment_utils::Commitment,
-    structures::produced_block::ProducedBlock,
-    traits::block_storage_interface::{BlockStorageInterface, BLOCK_NOT_FOUND},
+    structures::produced_block::{ProducedBlock, ProducedTransaction, TransactionInfo},
+    traits::block_storage_interface::{
+        BlockStorageInterface, BLOCK_NOT_FOUND, TRANSACTION_NOT_FOUND,
+    },
+};
+use solana_rpc_client_api::{
+    config::{RpcBlockConfig, RpcSignaturesForAddressConfig, RpcTransactionConfig},
+    request::MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT,
+    response::RpcConfirmedTransactionStatusWithSignature,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::{Bound, Range},
 };
-use solana_rpc_client_api::config::RpcBlockConfig;
-use solana_sdk::slot_history::Slot;
-use std::{collections::BTreeMap, ops::Range};
 use tokio::sync::RwLock;
 
 pub struct InmemoryBlockStore {
     block_storage: RwLock<BTreeMap<Slot, ProducedBlock>>,
+    // signature of every stored transaction to the slot of its block
+    signature_index: DashMap<String, Slot>,
+    // account to the position (slot, index in block) of the transactions referencing it
+    account_index: DashMap<Pubkey, BTreeSet<(Slot, usize)>>,
     number_of_blocks_to_store: usize,
 }
 
@@ -19,6 +33,8 @@ impl InmemoryBlockStore {
         Self {
             number_of_blocks_to_store,
             block_storage: RwLock::new(BTreeMap::new()),
+            signature_index: DashMap::new(),
+            account_index: DashMap::new(),
         }
     }
 
@@ -37,18 +53,77 @@ impl InmemoryBlockStore {
                     let commitment_block = Commitment::from(block.commitment_config);
                     let overwrite = commitment_block > commitment_store;
                     if overwrite {
+                        self.unindex_transactions(x);
+                        self.index_transactions(&block);
                         *x = block;
                     }
                 }
                 None => {
+                    self.index_transactions(&block);
                     block_storage.insert(slot, block);
                 }
             }
             if block_storage.len() > self.number_of_blocks_to_store {
-                block_storage.remove(&min_slot);
+                if let Some(removed_block) = block_storage.remove(&min_slot) {
+                    self.unindex_transactions(&removed_block);
+                }
+            }
+        }
+    }
+
+    fn index_transactions(&self, block: &ProducedBlock) {
+        for (index, tx) in block.transactions.iter().enumerate() {
+            self.signature_index
+                .insert(tx.signature.clone(), block.slot);
+            for account in Self::transaction_accounts(tx) {
+                self.account_index
+                    .entry(account)
+                    .or_default()
+                    .insert((block.slot, index));
             }
         }
     }
+
+    fn unindex_transactions(&self, block: &ProducedBlock) {
+        for (index, tx) in block.transactions.iter().enumerate() {
+            self.signature_index
+                .remove_if(&tx.signature, |_, slot| *slot == block.slot);
+            for account in Self::transaction_accounts(tx) {
+                self.account_index.remove_if_mut(&account, |_, positions| {
+                    positions.remove(&(block.slot, index));
+                    positions.is_empty()
+                });
+            }
+        }
+    }
+
+    fn transaction_accounts(tx: &TransactionInfo) -> Vec<Pubkey> {
+        match tx.account_keys() {
+            Ok(account_keys) => account_keys,
+            Err(e) => {
+                log::warn!(
+                    "Error decoding accounts of transaction {}: {e:?}",
+                    tx.signature
+                );
+                vec![]
+            }
+        }
+    }
+
+    // position of a stored transaction
+    fn transaction_position(
+        &self,
+        block_storage: &BTreeMap<Slot, ProducedBlock>,
+        signature: &str,
+    ) -> Option<(Slot, usize)> {
+        let slot = *self.signature_index.get(signature)?;
+        let index = block_storage
+            .get(&slot)?
+            .transactions
+            .iter()
+            .position(|tx| tx.signature == signature)?;
+        Some((slot, index))
+    }
 }
 
 #[async_trait]
@@ -80,4 +155,99 @@ impl BlockStorageInterface for InmemoryBlockStore {
             Range::default()
         }
     }
+
+    async fn get_slots_in_range(
+        &self,
+        range: Range<Slot>,
+        commitment_config: CommitmentConfig,
+    ) -> anyhow::Result<Vec<Slot>> {
+        if range.is_empty() {
+            return Ok(vec![]);
+        }
+        let commitment = Commitment::from(commitment_config);
+        Ok(self
+            .block_storage
+            .read()
+            .await
+            .range(range)
+            .filter(|(_, block)| Commitment::from(block.commitment_config) >= commitment)
+            .map(|(slot, _)| *slot)
+            .collect())
+    }
+
+    async fn get_transaction(
+        &self,
+        signature: &str,
+        _: RpcTransactionConfig,
+    ) -> anyhow::Result<ProducedTransaction> {
+        let Some(slot) = self.signature_index.get(signature).map(|slot| *slot) else {
+            anyhow::bail!(TRANSACTION_NOT_FOUND);
+        };
+
+        let block_storage = self.block_storage.read().await;
+        let block = block_storage
+            .get(&slot)
+            .ok_or(anyhow::Error::msg(TRANSACTION_NOT_FOUND))?;
+        let transaction = block
+            .transactions
+            .iter()
+            .find(|tx| tx.signature == signature)
+            .ok_or(anyhow::Error::msg(TRANSACTION_NOT_FOUND))?;
+
+        Ok(ProducedTransaction {
+            transaction: transaction.clone(),
+            slot,
+            block_time: block.block_time,
+            commitment_config: block.commitment_config,
+        })
+    }
+
+    async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        config: RpcSignaturesForAddressConfig,
+    ) -> anyhow::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let commitment = Commitment::from(config.commitment.unwrap_or_default());
+        let limit = config
+            .limit
+            .unwrap_or(MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT);
+        let block_storage = self.block_storage.read().await;
+
+        // an unknown before signature gives no results, an unknown until signature is ignored
+        let before = match &config.before {
+            Some(before) => match self.transaction_position(&block_storage, before) {
+                Some(position) => Bound::Excluded(position),
+                None => return Ok(vec![]),
+            },
+            None => Bound::Unbounded,
+        };
+        let until = match &config.until {
+            Some(until) => self
+                .transaction_position(&block_storage, until)
+                .map_or(Bound::Unbounded, Bound::Excluded),
+            None => Bound::Unbounded,
+        };
+        if let (Bound::Excluded(until), Bound::Excluded(before)) = (until, before) {
+            if until >= before {
+                return Ok(vec![]);
+            }
+        }
+
+        let Some(positions) = self.account_index.get(address) else {
+            return Ok(vec![]);
+        };
+        Ok(positions
+            .range((until, before))
+            .rev()
+            .filter_map(|(slot, index)| {
+                let block = block_storage.get(slot)?;
+                if Commitment::from(block.commitment_config) < commitment {
+                    return None;
+                }
+                let tx = block.transactions.get(*index)?;
+                Some(tx.to_signature_status(*slot, block.block_time, block.commitment_config))
+            })
+            .take(limit)
+            .collect())
+    }
 }
@@ -8,14 +8,27 @@ use anyhow::{bail, Result};
 use async_trait::async_trait;
 use solana_lite_rpc_core::{
     commitment_utils::Commitment,
-    structures::produced_block::ProducedBlock,
-    traits::block_storage_interface::{BlockStorageImpl, BlockStorageInterface, BLOCK_NOT_FOUND},
+    structures::produced_block::{ProducedBlock, ProducedTransaction, TransactionInfo},
+    traits::block_storage_interface::{
+        BlockStorageImpl, BlockStorageInterface, BLOCK_NOT_FOUND, TRANSACTION_NOT_FOUND,
+    },
+};
+use solana_rpc_client::{
+    nonblocking::rpc_client::RpcClient, rpc_client::GetConfirmedSignaturesForAddress2Config,
+};
+use solana_rpc_client_api::{
+    config::{RpcBlockConfig, RpcSignaturesForAddressConfig, RpcTransactionConfig},
+    request::MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT,
+    response::RpcConfirmedTransactionStatusWithSignature,
 };
-use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use solana_rpc_client_api::config::RpcBlockConfig;
-use solana_sdk::{commitment_config::CommitmentConfig, slot_history::Slot};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature, slot_history::Slot,
+};
+use solana_transaction_status::{TransactionDetails, UiTransactionEncoding};
 use std::{
+    collections::BTreeSet,
     ops::Range,
+    str::FromStr,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
@@ -111,14 +124,23 @@ impl BlockStorageInterface for MultipleStrategyBlockStorage {
             if persistent_block_range.contains(&slot) {
                 self.persistent_block_storage.get(slot, config).await
             } else if let Some(faithful_rpc_client) = self.faithful_rpc_client.clone() {
+                // fetch the full block, the encoding requested by the client is applied later
+                let faithful_config = RpcBlockConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    transaction_details: Some(TransactionDetails::Full),
+                    rewards: Some(true),
+                    commitment: config.commitment,
+                    max_supported_transaction_version: Some(0),
+                };
                 match faithful_rpc_client
-                    .get_block_with_config(slot, config)
+                    .get_block_with_config(slot, faithful_config)
                     .await
                 {
                     Ok(block) => Ok(ProducedBlock::from_ui_block(
                         block,
                         slot,
                         CommitmentConfig::finalized(),
+                        true,
                     )),
                     Err(_) => bail!(BLOCK_NOT_FOUND),
                 }
@@ -138,4 +160,195 @@ impl BlockStorageInterface for MultipleStrategyBlockStorage {
             persistent_storage_range.start..in_memory.end
         }
     }
+
+    async fn get_slots_in_range(
+        &self,
+        range: Range<Slot>,
+        commitment_config: CommitmentConfig,
+    ) -> Result<Vec<Slot>> {
+        let last_confirmed_slot = self.last_confirmed_slot.load(Ordering::Relaxed);
+        let range = range.start..range.end.min(last_confirmed_slot + 1);
+        if range.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut slots = BTreeSet::new();
+        slots.extend(
+            self.inmemory_for_storage
+                .get_slots_in_range(range.clone(), commitment_config)
+                .await?,
+        );
+
+        // blocks older than the ones kept in memory
+        let in_memory_range = self.inmemory_for_storage.get_slot_range().await;
+        let before_in_memory = if in_memory_range.is_empty() {
+            range
+        } else {
+            range.start..range.end.min(in_memory_range.start)
+        };
+        if before_in_memory.is_empty() {
+            return Ok(slots.into_iter().collect());
+        }
+        slots.extend(
+            self.persistent_block_storage
+                .get_slots_in_range(before_in_memory.clone(), commitment_config)
+                .await?,
+        );
+
+        // blocks older than the ones in persistent storage are fetched from faithful
+        let persistent_block_range = self.persistent_block_storage.get_slot_range().await;
+        let before_persistent = if persistent_block_range.is_empty() {
+            before_in_memory
+        } else {
+            before_in_memory.start..before_in_memory.end.min(persistent_block_range.start)
+        };
+        if !before_persistent.is_empty() {
+            if let Some(faithful_rpc_client) = self.faithful_rpc_client.clone() {
+                slots.extend(
+                    faithful_rpc_client
+                        .get_blocks_with_commitment(
+                            before_persistent.start,
+                            Some(before_persistent.end - 1),
+                            CommitmentConfig::finalized(),
+                        )
+                        .await?,
+                );
+            }
+        }
+        Ok(slots.into_iter().collect())
+    }
+
+    async fn get_transaction(
+        &self,
+        signature: &str,
+        config: RpcTransactionConfig,
+    ) -> Result<ProducedTransaction> {
+        if let Ok(transaction) = self
+            .inmemory_for_storage
+            .get_transaction(signature, config)
+            .await
+        {
+            return Ok(transaction);
+        }
+
+        if let Ok(transaction) = self
+            .persistent_block_storage
+            .get_transaction(signature, config)
+            .await
+        {
+            return Ok(transaction);
+        }
+
+        let Some(faithful_rpc_client) = self.faithful_rpc_client.clone() else {
+            bail!(TRANSACTION_NOT_FOUND);
+        };
+        // fetch the binary transaction, the encoding requested by the client is applied later
+        let faithful_config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: config.commitment,
+            max_supported_transaction_version: Some(0),
+        };
+        let Ok(transaction) = faithful_rpc_client
+            .get_transaction_with_config(&Signature::from_str(signature)?, faithful_config)
+            .await
+        else {
+            bail!(TRANSACTION_NOT_FOUND);
+        };
+        let Some(transaction_info) =
+            TransactionInfo::from_encoded_transaction(transaction.transaction, true)
+        else {
+            bail!(TRANSACTION_NOT_FOUND);
+        };
+
+        Ok(ProducedTransaction {
+            transaction: transaction_info,
+            slot: transaction.slot,
+            block_time: transaction.block_time.unwrap_or(0) as u64,
+            commitment_config: CommitmentConfig::finalized(),
+        })
+    }
+
+    async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        config: RpcSignaturesForAddressConfig,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let limit = config
+            .limit
+            .unwrap_or(MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT);
+        let mut signatures = self
+            .inmemory_for_storage
+            .get_signatures_for_address(address, config.clone())
+            .await?;
+        if signatures.len() >= limit {
+            return Ok(signatures);
+        }
+        // the until transaction is in memory so the older storages have nothing more to give
+        if let Some(until) = &config.until {
+            if self
+                .inmemory_for_storage
+                .get_transaction(until, RpcTransactionConfig::default())
+                .await
+                .is_ok()
+            {
+                return Ok(signatures);
+            }
+        }
+
+        // continue the search in the persistent storage from the oldest transaction found,
+        // a transaction that is not finalized yet is newer than everything stored there
+        let before = match signatures.last() {
+            Some(last) => Some(last.signature.clone()),
+            None => config.before.clone(),
+        };
+        let before = match &before {
+            Some(signature) => match self
+                .inmemory_for_storage
+                .get_transaction(signature, RpcTransactionConfig::default())
+                .await
+            {
+                Ok(transaction) if !transaction.commitment_config.is_finalized() => None,
+                _ => before,
+            },
+            None => None,
+        };
+        let persistent_config = RpcSignaturesForAddressConfig {
+            before,
+            limit: Some(limit - signatures.len()),
+            ..config.clone()
+        };
+        signatures.extend(
+            self.persistent_block_storage
+                .get_signatures_for_address(address, persistent_config.clone())
+                .await?,
+        );
+        if signatures.len() >= limit {
+            return Ok(signatures);
+        }
+
+        let Some(faithful_rpc_client) = self.faithful_rpc_client.clone() else {
+            return Ok(signatures);
+        };
+        let faithful_config = GetConfirmedSignaturesForAddress2Config {
+            before: match signatures.last() {
+                Some(last) => Some(Signature::from_str(&last.signature)?),
+                None => persistent_config
+                    .before
+                    .map(|before| Signature::from_str(&before))
+                    .transpose()?,
+            },
+            until: config
+                .until
+                .map(|until| Signature::from_str(&until))
+                .transpose()?,
+            limit: Some(limit - signatures.len()),
+            commitment: Some(CommitmentConfig::finalized()),
+        };
+        signatures.extend(
+            faithful_rpc_client
+                .get_signatures_for_address_with_config(address, faithful_config)
+                .await?,
+        );
+        Ok(signatures)
+    }
 }
@@ -1,18 +1,31 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use itertools::Itertools;
 use solana_lite_rpc_core::{
-    structures::{epoch::EpochCache, produced_block::ProducedBlock},
-    traits::block_storage_interface::BlockStorageInterface,
+    structures::{
+        epoch::EpochCache,
+        produced_block::{ProducedBlock, ProducedTransaction},
+    },
+    traits::block_storage_interface::{
+        BlockStorageInterface, BLOCK_NOT_FOUND, TRANSACTION_NOT_FOUND,
+    },
+};
+use solana_rpc_client_api::{
+    config::{RpcBlockConfig, RpcSignaturesForAddressConfig, RpcTransactionConfig},
+    request::MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT,
+    response::RpcConfirmedTransactionStatusWithSignature,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot, stake_history::Epoch,
 };
-use solana_rpc_client_api::config::RpcBlockConfig;
-use solana_sdk::{slot_history::Slot, stake_history::Epoch};
 use tokio::sync::RwLock;
 
 use crate::postgres::{
-    postgres_block::PostgresBlock, postgres_session::PostgresSessionCache,
+    postgres_account_signature::PostgresAccountSignature,
+    postgres_block::PostgresBlock,
+    postgres_session::{PostgresSession, PostgresSessionCache},
     postgres_transaction::PostgresTransaction,
 };
 
@@ -30,6 +43,14 @@ pub struct PostgresBlockStore {
 }
 
 impl PostgresBlockStore {
+    pub fn new(session_cache: PostgresSessionCache, epoch_cache: EpochCache) -> Self {
+        Self {
+            session_cache,
+            epoch_cache,
+            postgres_data: Arc::new(RwLock::new(PostgresData::default())),
+        }
+    }
+
     pub async fn start_new_epoch(&self, schema: &String) -> Result<()> {
         // create schema for new epoch
         let session = self
@@ -48,8 +69,52 @@ impl PostgresBlockStore {
         // create transaction table
         let statement = PostgresTransaction::create_statement(schema);
         session.execute(&statement, &[]).await?;
+
+        // create account signatures table
+        let statement = PostgresAccountSignature::create_statement(schema);
+        session.execute(&statement, &[]).await?;
+        let statement = PostgresAccountSignature::create_index_statement(schema);
+        session.execute(&statement, &[]).await?;
         Ok(())
     }
+
+    // epochs whose schema has been created, newest first
+    async fn get_stored_epochs(&self, session: &PostgresSession) -> Result<Vec<Epoch>> {
+        // unquoted schema names are stored in lower case
+        let statement = r#"
+                SELECT schema_name
+                FROM information_schema.schemata
+                WHERE schema_name LIKE 'epoch\_%'
+            "#
+        .to_string();
+        let rows = session.query(&statement, &[]).await?;
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                let schema: String = row.get(0);
+                schema.strip_prefix("epoch_")?.parse::<Epoch>().ok()
+            })
+            .sorted_by(|a, b| b.cmp(a))
+            .collect())
+    }
+
+    // position (slot, transaction index) of a transaction in the epoch schemas
+    async fn get_transaction_position(
+        &self,
+        session: &PostgresSession,
+        epochs: &[Epoch],
+        signature: &str,
+    ) -> Result<Option<(i64, i32)>> {
+        for epoch in epochs {
+            let schema = format!("EPOCH_{}", epoch);
+            if let Some(position) =
+                PostgresAccountSignature::get_position(session, &schema, signature).await?
+            {
+                return Ok(Some(position));
+            }
+        }
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -61,8 +126,16 @@ impl BlockStorageInterface for PostgresBlockStore {
         let transactions = block
             .transactions
             .iter()
-            .map(|x| PostgresTransaction::new(x, slot))
+            .enumerate()
+            .map(|(index, x)| PostgresTransaction::new(x, slot, index))
             .collect_vec();
+        let account_signatures = block
+            .transactions
+            .iter()
+            .enumerate()
+            .map(|(index, x)| PostgresAccountSignature::from_transaction(x, slot, index))
+            .flatten_ok()
+            .collect::<Result<Vec<_>>>()?;
         let postgres_block = PostgresBlock::from(&block);
 
         let epoch = self.epoch_cache.get_epoch_at_slot(slot);
@@ -81,21 +154,170 @@ impl BlockStorageInterface for PostgresBlockStore {
             .get_session()
             .await
             .expect("should get new postgres session");
+        // block is saved first as transactions reference it
+        postgres_block.save(&session, &schema).await?;
         for chunk in chunks {
             PostgresTransaction::save_transactions(&session, &schema, chunk).await?;
         }
-        postgres_block.save(&session, &schema).await?;
+        const NUMBER_OF_ACCOUNT_SIGNATURES: usize = 200;
+        for chunk in account_signatures.chunks(NUMBER_OF_ACCOUNT_SIGNATURES) {
+            PostgresAccountSignature::save_account_signatures(&session, &schema, chunk).await?;
+        }
+
+        let mut postgres_data = self.postgres_data.write().await;
+        if postgres_data.from_slot == 0 || slot < postgres_data.from_slot {
+            postgres_data.from_slot = slot;
+        }
+        if slot > postgres_data.to_slot {
+            postgres_data.to_slot = slot;
+        }
         Ok(())
     }
 
-    async fn get(&self, _slot: Slot, _config: RpcBlockConfig) -> Result<ProducedBlock> {
-        //let _range = self.get_slot_range().await;
-        //if range.contains(&slot) {}
-        todo!()
+    async fn get(&self, slot: Slot, _config: RpcBlockConfig) -> Result<ProducedBlock> {
+        let session = self.session_cache.get_session().await?;
+        let epoch = self.epoch_cache.get_epoch_at_slot(slot).epoch;
+        if !self.get_stored_epochs(&session).await?.contains(&epoch) {
+            bail!(BLOCK_NOT_FOUND);
+        }
+
+        let schema = format!("EPOCH_{}", epoch);
+        let Some(block) = PostgresBlock::get(&session, &schema, slot).await? else {
+            bail!(BLOCK_NOT_FOUND);
+        };
+        let transactions = PostgresTransaction::get(&session, &schema, slot).await?;
+        // only finalized blocks are saved in postgres
+        block.into_produced_block(transactions, CommitmentConfig::finalized())
     }
 
-    async fn get_slot_range(&self) -> std::ops::Range<Slot> {
+    async fn get_slot_range(&self) -> Range<Slot> {
         let lk = self.postgres_data.read().await;
+        if lk.to_slot == 0 {
+            // nothing saved yet
+            return Range::default();
+        }
         lk.from_slot..lk.to_slot + 1
     }
+
+    async fn get_slots_in_range(
+        &self,
+        range: Range<Slot>,
+        _commitment_config: CommitmentConfig,
+    ) -> Result<Vec<Slot>> {
+        // only finalized blocks are saved in postgres
+        let stored_range = self.get_slot_range().await;
+        let range = range.start.max(stored_range.start)..range.end.min(stored_range.end);
+        if range.is_empty() {
+            return Ok(vec![]);
+        }
+        let first_epoch = self.epoch_cache.get_epoch_at_slot(range.start).epoch;
+        let last_epoch = self.epoch_cache.get_epoch_at_slot(range.end - 1).epoch;
+
+        let session = self.session_cache.get_session().await?;
+        let mut slots = vec![];
+        for epoch in first_epoch..=last_epoch {
+            let schema = format!("EPOCH_{}", epoch);
+            match PostgresBlock::get_slots(&session, &schema, &range).await {
+                Ok(epoch_slots) => slots.extend(epoch_slots),
+                Err(e) => {
+                    log::warn!("Error getting slots from schema {schema}: {e:?}");
+                }
+            }
+        }
+        Ok(slots)
+    }
+
+    async fn get_transaction(
+        &self,
+        signature: &str,
+        _config: RpcTransactionConfig,
+    ) -> Result<ProducedTransaction> {
+        let session = self.session_cache.get_session().await?;
+        // the signature is the primary key of the transaction table of each epoch
+        for epoch in self.get_stored_epochs(&session).await? {
+            let schema = format!("EPOCH_{}", epoch);
+            if let Some((transaction, block_time)) =
+                PostgresTransaction::get_by_signature(&session, &schema, signature).await?
+            {
+                return Ok(ProducedTransaction {
+                    slot: transaction.slot as Slot,
+                    transaction: transaction.to_transaction_info()?,
+                    block_time: block_time as u64,
+                    commitment_config: CommitmentConfig::finalized(),
+                });
+            }
+        }
+        bail!(TRANSACTION_NOT_FOUND)
+    }
+
+    async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        config: RpcSignaturesForAddressConfig,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let limit = config
+            .limit
+            .unwrap_or(MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT);
+
+        let session = self.session_cache.get_session().await?;
+        let stored_epochs = self.get_stored_epochs(&session).await?;
+        // an unknown before signature gives no results, an unknown until signature is ignored
+        let before = match &config.before {
+            Some(before) => {
+                let Some(position) = self
+                    .get_transaction_position(&session, &stored_epochs, before)
+                    .await?
+                else {
+                    return Ok(vec![]);
+                };
+                position
+            }
+            None => (i64::MAX, i32::MAX),
+        };
+        let until = match &config.until {
+            Some(until) => self
+                .get_transaction_position(&session, &stored_epochs, until)
+                .await?
+                .unwrap_or((-1, -1)),
+            None => (-1, -1),
+        };
+
+        // only the epochs between the until and before transactions are searched
+        let last_epoch = config
+            .before
+            .is_some()
+            .then(|| self.epoch_cache.get_epoch_at_slot(before.0 as Slot).epoch);
+        let first_epoch =
+            (until.0 >= 0).then(|| self.epoch_cache.get_epoch_at_slot(until.0 as Slot).epoch);
+        let epochs = stored_epochs.into_iter().filter(|epoch| {
+            last_epoch.map_or(true, |last_epoch| *epoch <= last_epoch)
+                && first_epoch.map_or(true, |first_epoch| *epoch >= first_epoch)
+        });
+
+        let account = address.to_string();
+        let mut signatures = vec![];
+        for epoch in epochs {
+            if signatures.len() >= limit {
+                break;
+            }
+            let schema = format!("EPOCH_{}", epoch);
+            let transactions = PostgresAccountSignature::get_transactions(
+                &session,
+                &schema,
+                &account,
+                before,
+                until,
+                (limit - signatures.len()) as i64,
+            )
+            .await?;
+            for (transaction, block_time) in transactions {
+                signatures.push(transaction.to_transaction_info()?.to_signature_status(
+                    transaction.slot as Slot,
+                    block_time as u64,
+                    CommitmentConfig::finalized(),
+                ));
+            }
+        }
+        Ok(signatures)
+    }
 }
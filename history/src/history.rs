@@ -1,6 +1,35 @@
-use solana_lite_rpc_core::traits::block_storage_interface::BlockStorageInterface;
+use anyhow::bail;
+use solana_lite_rpc_core::{
+    traits::block_storage_interface::BlockStorageInterface, types::BlockStream, AnyhowJoinHandle,
+};
 use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
 
 pub struct History {
     pub block_storage: Arc<dyn BlockStorageInterface>,
 }
+
+impl History {
+    /// save all the blocks received from the cluster so that they can be served later
+    pub fn start_saving_blocks(&self, block_notifier: BlockStream) -> AnyhowJoinHandle {
+        let block_storage = self.block_storage.clone();
+        tokio::spawn(async move {
+            let mut block_notifier = block_notifier;
+            loop {
+                match block_notifier.recv().await {
+                    Ok(block) => {
+                        if let Err(e) = block_storage.save(block).await {
+                            log::error!("Error saving block in history {e:?}");
+                        }
+                    }
+                    Err(RecvError::Lagged(missed)) => {
+                        log::warn!("History block saving lagged, missed {missed} blocks");
+                    }
+                    Err(e) => {
+                        bail!("Error in block notification {e:?}");
+                    }
+                }
+            }
+        })
+    }
+}
@@ -1,3 +1,4 @@
+pub mod postgres_account_signature;
 pub mod postgres_block;
 pub mod postgres_config;
 pub mod postgres_session;
@@ -0,0 +1,158 @@
+use solana_lite_rpc_core::structures::produced_block::TransactionInfo;
+use solana_sdk::slot_history::Slot;
+use tokio_postgres::types::ToSql;
+
+use super::{
+    postgres_session::PostgresSession,
+    postgres_transaction::{self, PostgresTransaction},
+};
+
+#[derive(Debug)]
+pub struct PostgresAccountSignature {
+    pub account: String,
+    pub signature: String,
+    pub slot: i64,
+    pub transaction_index: i32,
+}
+
+const NB_ARUMENTS: usize = 4;
+
+impl PostgresAccountSignature {
+    /// one row for every account referenced by the transaction
+    pub fn from_transaction(
+        value: &TransactionInfo,
+        slot: Slot,
+        transaction_index: usize,
+    ) -> anyhow::Result<Vec<Self>> {
+        Ok(value
+            .account_keys()?
+            .iter()
+            .map(|account| Self {
+                account: account.to_string(),
+                signature: value.signature.clone(),
+                slot: slot as i64,
+                transaction_index: transaction_index as i32,
+            })
+            .collect())
+    }
+
+    pub fn create_statement(schema: &String) -> String {
+        format!(
+            "\
+        CREATE TABLE {}.ACCOUNT_SIGNATURES (
+            account CHAR(44) NOT NULL,
+            signature CHAR(88) NOT NULL,
+            slot BIGINT NOT NULL,
+            transaction_index INTEGER NOT NULL,
+            PRIMARY KEY (account, slot, transaction_index),
+            CONSTRAINT fk_account_signatures FOREIGN KEY (signature) REFERENCES {}.TRANSACTIONS(signature)
+          );
+        ",
+            schema, schema
+        )
+    }
+
+    // before/until signatures are searched by signature
+    pub fn create_index_statement(schema: &String) -> String {
+        format!(
+            "CREATE INDEX account_signatures_signature ON {}.ACCOUNT_SIGNATURES (signature);",
+            schema
+        )
+    }
+
+    pub async fn save_account_signatures(
+        postgres_session: &PostgresSession,
+        schema: &String,
+        account_signatures: &[Self],
+    ) -> anyhow::Result<()> {
+        let mut args: Vec<&(dyn ToSql + Sync)> =
+            Vec::with_capacity(NB_ARUMENTS * account_signatures.len());
+
+        for account_signature in account_signatures.iter() {
+            let PostgresAccountSignature {
+                account,
+                signature,
+                slot,
+                transaction_index,
+            } = account_signature;
+
+            args.push(account);
+            args.push(signature);
+            args.push(slot);
+            args.push(transaction_index);
+        }
+
+        let mut query = format!(
+            r#"
+                INSERT INTO {}.ACCOUNT_SIGNATURES
+                (account, signature, slot, transaction_index)
+                VALUES
+            "#,
+            schema
+        );
+
+        PostgresSession::multiline_query(&mut query, NB_ARUMENTS, account_signatures.len(), &[]);
+        postgres_session.execute(&query, &args).await?;
+        Ok(())
+    }
+
+    /// position (slot, transaction index) of a transaction in the schema
+    pub async fn get_position(
+        postgres_session: &PostgresSession,
+        schema: &String,
+        signature: &str,
+    ) -> anyhow::Result<Option<(i64, i32)>> {
+        let statement = format!(
+            r#"
+                SELECT slot, transaction_index
+                FROM {}.ACCOUNT_SIGNATURES
+                WHERE signature = $1
+                LIMIT 1
+            "#,
+            schema
+        );
+        let rows = postgres_session.query(&statement, &[&signature]).await?;
+        Ok(rows.first().map(|row| (row.get(0), row.get(1))))
+    }
+
+    /// transactions referencing the account strictly between the two positions, newest first,
+    /// along with the block time of their block
+    pub async fn get_transactions(
+        postgres_session: &PostgresSession,
+        schema: &String,
+        account: &str,
+        before: (i64, i32),
+        until: (i64, i32),
+        limit: i64,
+    ) -> anyhow::Result<Vec<(PostgresTransaction, i64)>> {
+        let statement = format!(
+            r#"
+                SELECT t.signature, t.slot, t.transaction_index, t.err, t.cu_requested, t.prioritization_fees, t.cu_consumed, t.recent_blockhash, t.message, t.signatures, t.meta, b.block_time
+                FROM {}.ACCOUNT_SIGNATURES a
+                INNER JOIN {}.TRANSACTIONS t ON a.signature = t.signature
+                INNER JOIN {}.BLOCKS b ON a.slot = b.slot
+                WHERE a.account = $1
+                AND (a.slot, a.transaction_index) < ($2, $3)
+                AND (a.slot, a.transaction_index) > ($4, $5)
+                ORDER BY a.slot DESC, a.transaction_index DESC
+                LIMIT $6
+            "#,
+            schema, schema, schema
+        );
+        let rows = postgres_session
+            .query(
+                &statement,
+                &[&account, &before.0, &before.1, &until.0, &until.1, &limit],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    PostgresTransaction::from_row(row),
+                    row.get(postgres_transaction::NB_ARUMENTS),
+                )
+            })
+            .collect())
+    }
+}
@@ -1,4 +1,10 @@
-use solana_lite_rpc_core::{encoding::BASE64, structures::produced_block::ProducedBlock};
+use std::ops::Range;
+
+use solana_lite_rpc_core::{
+    encoding::BASE64,
+    structures::produced_block::{ProducedBlock, TransactionInfo},
+};
+use solana_sdk::{commitment_config::CommitmentConfig, slot_history::Slot};
 use tokio_postgres::types::ToSql;
 
 use super::postgres_session::PostgresSession;
@@ -42,13 +48,13 @@ impl PostgresBlock {
             "
             CREATE TABLE {}.BLOCKS (
                 slot BIGINT PRIMARY KEY,
-                blockhash STRING NOT NULL,
-                leader_id STRING,
+                blockhash TEXT NOT NULL,
+                leader_id TEXT,
                 block_height BIGINT NOT NULL,
                 parent_slot BIGINT NOT NULL,
                 block_time BIGINT NOT NULL,
-                previous_blockhash STRING NOT NULL,
-                rewards STRING,
+                previous_blockhash TEXT NOT NULL,
+                rewards TEXT
             );
         ",
             schema
@@ -80,4 +86,75 @@ impl PostgresBlock {
         postgres_session.execute(&query, &args).await?;
         Ok(())
     }
+
+    pub async fn get_slots(
+        postgres_session: &PostgresSession,
+        schema: &String,
+        range: &Range<Slot>,
+    ) -> anyhow::Result<Vec<Slot>> {
+        let statement = format!(
+            r#"
+                SELECT slot FROM {}.BLOCKS
+                WHERE slot >= $1 AND slot < $2
+                ORDER BY slot
+            "#,
+            schema
+        );
+        let rows = postgres_session
+            .query(&statement, &[&(range.start as i64), &(range.end as i64)])
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| row.get::<_, i64>(0) as Slot)
+            .collect())
+    }
+
+    pub async fn get(
+        postgres_session: &PostgresSession,
+        schema: &String,
+        slot: Slot,
+    ) -> anyhow::Result<Option<Self>> {
+        let statement = format!(
+            r#"
+                SELECT slot, blockhash, block_height, parent_slot, block_time, previous_blockhash, rewards
+                FROM {}.BLOCKS
+                WHERE slot = $1
+            "#,
+            schema
+        );
+        let rows = postgres_session
+            .query(&statement, &[&(slot as i64)])
+            .await?;
+        Ok(rows.first().map(|row| Self {
+            slot: row.get(0),
+            blockhash: row.get(1),
+            block_height: row.get(2),
+            parent_slot: row.get(3),
+            block_time: row.get(4),
+            previous_blockhash: row.get(5),
+            rewards: row.get(6),
+        }))
+    }
+
+    pub fn into_produced_block(
+        self,
+        transactions: Vec<TransactionInfo>,
+        commitment_config: CommitmentConfig,
+    ) -> anyhow::Result<ProducedBlock> {
+        Ok(ProducedBlock {
+            transactions,
+            leader_id: None,
+            blockhash: self.blockhash,
+            block_height: self.block_height as u64,
+            slot: self.slot as Slot,
+            parent_slot: self.parent_slot as Slot,
+            block_time: self.block_time as u64,
+            commitment_config,
+            previous_blockhash: self.previous_blockhash,
+            rewards: match self.rewards {
+                Some(rewards) => Some(BASE64.deserialize(&rewards)?),
+                None => None,
+            },
+        })
+    }
 }
@@ -5,7 +5,9 @@ use native_tls::{Certificate, Identity, TlsConnector};
 use postgres_native_tls::MakeTlsConnector;
 use solana_lite_rpc_core::encoding::BinaryEncoding;
 use tokio::sync::RwLock;
-use tokio_postgres::{config::SslMode, tls::MakeTlsConnect, types::ToSql, Client, NoTls, Socket};
+use tokio_postgres::{
+    config::SslMode, tls::MakeTlsConnect, types::ToSql, Client, NoTls, Row, Socket,
+};
 
 use super::postgres_config::{PostgresSessionConfig, PostgresSessionSslConfig};
 
@@ -132,6 +134,14 @@ impl PostgresSession {
     ) -> Result<u64, tokio_postgres::error::Error> {
         self.client.execute(statement, params).await
     }
+
+    pub async fn query(
+        &self,
+        statement: &String,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, tokio_postgres::error::Error> {
+        self.client.query(statement, params).await
+    }
 }
 
 #[derive(Clone)]
@@ -1,6 +1,10 @@
-use solana_lite_rpc_core::{encoding::BASE64, structures::produced_block::TransactionInfo};
+use solana_lite_rpc_core::{
+    encoding::BASE64,
+    structures::produced_block::{transaction_status_meta_from_ui, TransactionInfo},
+};
 use solana_sdk::slot_history::Slot;
-use tokio_postgres::types::ToSql;
+use solana_transaction_status::UiTransactionStatusMeta;
+use tokio_postgres::{types::ToSql, Row};
 
 use super::postgres_session::PostgresSession;
 
@@ -8,18 +12,21 @@ use super::postgres_session::PostgresSession;
 pub struct PostgresTransaction {
     pub signature: String,
     pub slot: i64,
+    pub transaction_index: i32,
     pub err: Option<String>,
     pub cu_requested: Option<i32>,
     pub prioritization_fees: Option<i64>,
     pub cu_consumed: Option<i64>,
     pub recent_blockhash: String,
     pub message: String,
+    pub signatures: Vec<String>,
+    pub meta: Option<String>,
 }
 
-const NB_ARUMENTS: usize = 8;
+pub(crate) const NB_ARUMENTS: usize = 11;
 
 impl PostgresTransaction {
-    pub fn new(value: &TransactionInfo, slot: Slot) -> Self {
+    pub fn new(value: &TransactionInfo, slot: Slot, transaction_index: usize) -> Self {
         Self {
             signature: value.signature.clone(),
             err: value
@@ -33,6 +40,14 @@ impl PostgresTransaction {
             recent_blockhash: value.recent_blockhash.clone(),
             message: value.message.clone(),
             slot: slot as i64,
+            transaction_index: transaction_index as i32,
+            signatures: value.signatures.clone(),
+            // meta is stored in its json rpc representation
+            meta: value
+                .meta
+                .clone()
+                .map(|meta| serde_json::to_string(&UiTransactionStatusMeta::from(meta)).ok())
+                .unwrap_or(None),
         }
     }
 
@@ -41,15 +56,18 @@ impl PostgresTransaction {
             "\
         CREATE TABLE {}.TRANSACTIONS (
             signature CHAR(88) NOT NULL,
-            slot BIGINT, 
-            err STRING,
-            cu_requested BIGINT,
+            slot BIGINT NOT NULL,
+            transaction_index INTEGER NOT NULL,
+            err TEXT,
+            cu_requested INTEGER,
             prioritization_fees BIGINT,
             cu_consumed BIGINT,
-            recent_blockhash STRING NOT NULL,
-            message STRING NOT NULL,
-            PRIMARY KEY (signature)
-            CONSTRAINT fk_transactions FOREIGN KEY (slot) REFERENCES {}.BLOCKS(slot);
+            recent_blockhash TEXT NOT NULL,
+            message TEXT NOT NULL,
+            signatures TEXT[] NOT NULL,
+            meta TEXT,
+            PRIMARY KEY (signature),
+            CONSTRAINT fk_transactions FOREIGN KEY (slot) REFERENCES {}.BLOCKS(slot)
           );
         ",
             schema, schema
@@ -68,28 +86,34 @@ impl PostgresTransaction {
             let PostgresTransaction {
                 signature,
                 slot,
+                transaction_index,
                 err,
                 cu_requested,
                 prioritization_fees,
                 cu_consumed,
                 recent_blockhash,
                 message,
+                signatures,
+                meta,
             } = tx;
 
             args.push(signature);
             args.push(slot);
+            args.push(transaction_index);
             args.push(err);
             args.push(cu_requested);
             args.push(prioritization_fees);
             args.push(cu_consumed);
             args.push(recent_blockhash);
             args.push(message);
+            args.push(signatures);
+            args.push(meta);
         }
 
         let mut query = format!(
             r#"
-                INSERT INTO {}.TRANSACTIONS 
-                (signature, slot, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message)
+                INSERT INTO {}.TRANSACTIONS
+                (signature, slot, transaction_index, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message, signatures, meta)
                 VALUES
             "#,
             schema
@@ -100,13 +124,87 @@ impl PostgresTransaction {
         Ok(())
     }
 
+    /// transactions of the block in their order in the block
     pub async fn get(
-        postgres_session: PostgresSession,
+        postgres_session: &PostgresSession,
         schema: &String,
         slot: Slot,
-    ) -> Vec<TransactionInfo> {
-        let statement = format!("SELECT signature, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message FROM {}.TRANSACTIONS WHERE SLOT = {}", schema, slot);
-        let _ = postgres_session.client.query(&statement, &[]).await;
-        todo!()
+    ) -> anyhow::Result<Vec<TransactionInfo>> {
+        let statement = format!(
+            r#"
+                SELECT signature, slot, transaction_index, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message, signatures, meta
+                FROM {}.TRANSACTIONS
+                WHERE slot = $1
+                ORDER BY transaction_index
+            "#,
+            schema
+        );
+        let rows = postgres_session
+            .query(&statement, &[&(slot as i64)])
+            .await?;
+        rows.iter()
+            .map(|row| Self::from_row(row).to_transaction_info())
+            .collect()
+    }
+
+    /// get a transaction by signature along with the block time of its block
+    pub async fn get_by_signature(
+        postgres_session: &PostgresSession,
+        schema: &String,
+        signature: &str,
+    ) -> anyhow::Result<Option<(Self, i64)>> {
+        let statement = format!(
+            r#"
+                SELECT t.signature, t.slot, t.transaction_index, t.err, t.cu_requested, t.prioritization_fees, t.cu_consumed, t.recent_blockhash, t.message, t.signatures, t.meta, b.block_time
+                FROM {}.TRANSACTIONS t INNER JOIN {}.BLOCKS b ON t.slot = b.slot
+                WHERE t.signature = $1
+            "#,
+            schema, schema
+        );
+        let rows = postgres_session.query(&statement, &[&signature]).await?;
+        Ok(rows
+            .first()
+            .map(|row| (Self::from_row(row), row.get(NB_ARUMENTS))))
+    }
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        Self {
+            signature: row.get(0),
+            slot: row.get(1),
+            transaction_index: row.get(2),
+            err: row.get(3),
+            cu_requested: row.get(4),
+            prioritization_fees: row.get(5),
+            cu_consumed: row.get(6),
+            recent_blockhash: row.get(7),
+            message: row.get(8),
+            signatures: row.get(9),
+            meta: row.get(10),
+        }
+    }
+
+    pub fn to_transaction_info(&self) -> anyhow::Result<TransactionInfo> {
+        let meta = match &self.meta {
+            Some(meta) => {
+                let meta: UiTransactionStatusMeta = serde_json::from_str(meta)?;
+                Some(transaction_status_meta_from_ui(meta)?)
+            }
+            None => None,
+        };
+
+        Ok(TransactionInfo {
+            signature: self.signature.trim().to_string(),
+            err: match &self.err {
+                Some(err) => Some(BASE64.deserialize(err)?),
+                None => None,
+            },
+            cu_requested: self.cu_requested.map(|x| x as u32),
+            prioritization_fees: self.prioritization_fees.map(|x| x as u64),
+            cu_consumed: self.cu_consumed.map(|x| x as u64),
+            recent_blockhash: self.recent_blockhash.clone(),
+            message: self.message.clone(),
+            signatures: self.signatures.clone(),
+            meta,
+        })
     }
 }
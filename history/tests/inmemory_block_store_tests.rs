@@ -1,10 +1,20 @@
 use solana_lite_rpc_core::{
-    structures::produced_block::ProducedBlock,
+    encoding::BinaryEncoding,
+    structures::produced_block::{ProducedBlock, TransactionInfo},
     traits::block_storage_interface::BlockStorageInterface,
 };
 use solana_lite_rpc_history::block_stores::inmemory_block_store::InmemoryBlockStore;
-use solana_rpc_client_api::config::RpcBlockConfig;
-use solana_sdk::{commitment_config::CommitmentConfig, hash::Hash};
+use solana_rpc_client_api::config::{
+    RpcBlockConfig, RpcSignaturesForAddressConfig, RpcTransactionConfig,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+};
 use std::sync::Arc;
 
 pub fn create_test_block(slot: u64, commitment_config: CommitmentConfig) -> ProducedBlock {
@@ -61,3 +71,157 @@ async fn inmemory_block_store_tests() {
         .unwrap();
     assert!(store.get(1, RpcBlockConfig::default()).await.ok().is_none());
 }
+
+pub fn create_test_transaction() -> TransactionInfo {
+    let signature = Signature::new_unique().to_string();
+    TransactionInfo {
+        signature: signature.clone(),
+        err: None,
+        cu_requested: None,
+        prioritization_fees: None,
+        cu_consumed: None,
+        recent_blockhash: Hash::new_unique().to_string(),
+        message: String::new(),
+        signatures: vec![signature],
+        meta: None,
+    }
+}
+
+#[tokio::test]
+async fn inmemory_block_store_transaction_tests() {
+    let store: Arc<dyn BlockStorageInterface> = Arc::new(InmemoryBlockStore::new(2));
+
+    let transaction = create_test_transaction();
+    let mut block = create_test_block(1, CommitmentConfig::confirmed());
+    block.transactions = vec![transaction.clone()];
+    store.save(block).await.unwrap();
+
+    let produced = store
+        .get_transaction(&transaction.signature, RpcTransactionConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(produced.slot, 1);
+    assert_eq!(produced.transaction.signature, transaction.signature);
+    assert_eq!(produced.commitment_config, CommitmentConfig::confirmed());
+
+    // unknown signature
+    assert!(store
+        .get_transaction(
+            &Signature::new_unique().to_string(),
+            RpcTransactionConfig::default()
+        )
+        .await
+        .is_err());
+
+    // the transaction is no longer indexed once its block is evicted
+    for i in 2..4 {
+        store
+            .save(create_test_block(i, CommitmentConfig::finalized()))
+            .await
+            .unwrap();
+    }
+    assert!(store
+        .get_transaction(&transaction.signature, RpcTransactionConfig::default())
+        .await
+        .is_err());
+}
+
+pub fn create_test_transaction_with_accounts(accounts: &[Pubkey]) -> TransactionInfo {
+    let message = VersionedMessage::Legacy(Message::new(
+        &[Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            accounts
+                .iter()
+                .map(|account| AccountMeta::new(*account, false))
+                .collect(),
+        )],
+        Some(&Pubkey::new_unique()),
+    ));
+    TransactionInfo {
+        message: BinaryEncoding::Base64.encode(bincode::serialize(&message).unwrap()),
+        ..create_test_transaction()
+    }
+}
+
+#[tokio::test]
+async fn inmemory_block_store_signatures_for_address_tests() {
+    let store: Arc<dyn BlockStorageInterface> = Arc::new(InmemoryBlockStore::new(10));
+    let account = Pubkey::new_unique();
+
+    // two transactions referencing the account in each block, one which does not
+    let mut signatures = vec![];
+    for slot in 1..4 {
+        let transactions = vec![
+            create_test_transaction_with_accounts(&[account]),
+            create_test_transaction_with_accounts(&[Pubkey::new_unique()]),
+            create_test_transaction_with_accounts(&[account]),
+        ];
+        signatures.push(transactions[0].signature.clone());
+        signatures.push(transactions[2].signature.clone());
+        let commitment_config = if slot == 3 {
+            CommitmentConfig::confirmed()
+        } else {
+            CommitmentConfig::finalized()
+        };
+        let mut block = create_test_block(slot, commitment_config);
+        block.transactions = transactions;
+        store.save(block).await.unwrap();
+    }
+    // newest first
+    signatures.reverse();
+
+    let get_signatures = |config: RpcSignaturesForAddressConfig| {
+        let store = store.clone();
+        async move {
+            store
+                .get_signatures_for_address(&account, config)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|status| status.signature)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    let confirmed = Some(CommitmentConfig::confirmed());
+    assert_eq!(
+        get_signatures(RpcSignaturesForAddressConfig {
+            commitment: confirmed,
+            ..Default::default()
+        })
+        .await,
+        signatures
+    );
+    assert_eq!(
+        get_signatures(RpcSignaturesForAddressConfig {
+            limit: Some(3),
+            commitment: confirmed,
+            ..Default::default()
+        })
+        .await,
+        signatures[..3]
+    );
+    assert_eq!(
+        get_signatures(RpcSignaturesForAddressConfig {
+            before: Some(signatures[1].clone()),
+            until: Some(signatures[4].clone()),
+            commitment: confirmed,
+            ..Default::default()
+        })
+        .await,
+        signatures[2..4]
+    );
+    // the confirmed block is not listed for finalized commitment
+    assert_eq!(
+        get_signatures(RpcSignaturesForAddressConfig::default()).await,
+        signatures[2..]
+    );
+    // unknown before signature
+    assert!(get_signatures(RpcSignaturesForAddressConfig {
+        before: Some(Signature::new_unique().to_string()),
+        ..Default::default()
+    })
+    .await
+    .is_empty());
+}
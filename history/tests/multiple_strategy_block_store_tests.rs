@@ -155,3 +155,49 @@ async fn test_in_multiple_stategy_block_store() {
         .ok()
         .is_none());
 }
+
+#[tokio::test]
+async fn test_slots_in_range_multiple_stategy_block_store() {
+    let persistent_store: Arc<dyn BlockStorageInterface> = Arc::new(InmemoryBlockStore::new(10));
+    let block_storage = MultipleStrategyBlockStorage::new(persistent_store.clone(), None, 2);
+
+    // 1002 is skipped
+    for slot in [1000, 1001, 1003] {
+        block_storage
+            .save(create_test_block(slot, CommitmentConfig::finalized()))
+            .await
+            .unwrap();
+    }
+    block_storage
+        .save(create_test_block(1004, CommitmentConfig::confirmed()))
+        .await
+        .unwrap();
+
+    // older slots come from the persistent storage, newer ones from memory
+    assert_eq!(
+        block_storage
+            .get_slots_in_range(990..1010, CommitmentConfig::confirmed())
+            .await
+            .unwrap(),
+        vec![1000, 1001, 1003, 1004]
+    );
+    assert_eq!(
+        block_storage
+            .get_slots_in_range(990..1010, CommitmentConfig::finalized())
+            .await
+            .unwrap(),
+        vec![1000, 1001, 1003]
+    );
+    assert_eq!(
+        block_storage
+            .get_slots_in_range(1001..1004, CommitmentConfig::confirmed())
+            .await
+            .unwrap(),
+        vec![1001, 1003]
+    );
+    assert!(block_storage
+        .get_slots_in_range(1005..1010, CommitmentConfig::confirmed())
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,430 @@
+use solana_account_decoder::{UiAccount, UiAccountEncoding, UiDataSliceConfig};
+use solana_lite_rpc_core::{
+    commitment_utils::Commitment,
+    structures::account_data::{
+        AccountData, AccountNotificationMessage, AccountStreamFilter, AccountStreamFilters,
+    },
+    types::AccountFiltersSender,
+};
+use solana_rpc_client_api::{
+    filter::RpcFilterType,
+    response::{Response as RpcResponse, RpcKeyedAccount, RpcResponseContext},
+};
+use solana_sdk::{account::AccountSharedData, pubkey::Pubkey};
+use std::collections::{BTreeSet, HashMap};
+use tokio::sync::broadcast;
+
+// account updates buffered for each subscription before the slowest ones start to skip some
+const ACCOUNT_NOTIFICATIONS_CAPACITY: usize = 1000;
+
+type AccountSubscribers = Vec<broadcast::Sender<AccountData>>;
+// the filters of each program subscription
+type ProgramSubscribers = Vec<(Vec<RpcFilterType>, broadcast::Sender<AccountData>)>;
+
+/// Account and program subscriptions indexed by commitment and pubkey.
+///
+/// The accounts and programs streamed by the cluster endpoint are kept in line with
+/// the subscriptions so only the subscribed accounts are notified by geyser. The filters of
+/// a program are applied by geyser when all its subscriptions share them.
+pub struct AccountSubscriptions {
+    accounts: HashMap<(Commitment, Pubkey), AccountSubscribers>,
+    programs: HashMap<(Commitment, Pubkey), ProgramSubscribers>,
+    filters_sender: AccountFiltersSender,
+    // programs of the account cache, streamed at all commitments
+    cached_programs: BTreeSet<Pubkey>,
+    // accounts owned by the cached programs, also streamed by pubkey at all commitments so their
+    // closing and owner changes are notified
+    cached_accounts: BTreeSet<Pubkey>,
+}
+
+impl AccountSubscriptions {
+    pub fn new(filters_sender: AccountFiltersSender, cached_programs: BTreeSet<Pubkey>) -> Self {
+        let account_subscriptions = Self {
+            accounts: HashMap::new(),
+            programs: HashMap::new(),
+            filters_sender,
+            cached_programs,
+            cached_accounts: BTreeSet::new(),
+        };
+        account_subscriptions.update_filters();
+        account_subscriptions
+    }
+
+    pub fn subscribe_account(
+        &mut self,
+        pubkey: Pubkey,
+        commitment: Commitment,
+    ) -> broadcast::Receiver<AccountData> {
+        let (sender, receiver) = broadcast::channel(ACCOUNT_NOTIFICATIONS_CAPACITY);
+        self.accounts
+            .entry((commitment, pubkey))
+            .or_default()
+            .push(sender);
+        self.remove_closed();
+        receiver
+    }
+
+    /// Updates of the accounts owned by the program, the accounts not matching the filters
+    /// may be notified too
+    pub fn subscribe_program(
+        &mut self,
+        program_id: Pubkey,
+        filters: Vec<RpcFilterType>,
+        commitment: Commitment,
+    ) -> broadcast::Receiver<AccountData> {
+        let (sender, receiver) = broadcast::channel(ACCOUNT_NOTIFICATIONS_CAPACITY);
+        self.programs
+            .entry((commitment, program_id))
+            .or_default()
+            .push((filters, sender));
+        self.remove_closed();
+        receiver
+    }
+
+    /// Returns true when the streamed accounts have to be updated with `update_streamed`,
+    /// a subscription has been closed by its client or the account of a cached program isn't
+    /// streamed by its pubkey yet
+    pub fn notify(&self, message: &AccountNotificationMessage) -> bool {
+        let AccountNotificationMessage { data, commitment } = message;
+        let account_subscribers = self.accounts.get(&(*commitment, data.pubkey));
+        let program_subscribers = self
+            .programs
+            .get(&(*commitment, data.account.owner))
+            .into_iter()
+            .flatten()
+            .map(|(_, sender)| sender);
+        let mut has_closed = false;
+        for sender in account_subscribers
+            .into_iter()
+            .flatten()
+            .chain(program_subscribers)
+        {
+            if sender.send(data.clone()).is_err() {
+                has_closed = true;
+            }
+        }
+        has_closed
+            || (self.cached_programs.contains(&data.account.owner)
+                && !self.cached_accounts.contains(&data.pubkey))
+    }
+
+    /// Stream the account by its pubkey when it is owned by a cached program and remove
+    /// the closed subscriptions
+    pub fn update_streamed(&mut self, data: &AccountData) {
+        if self.cached_programs.contains(&data.account.owner) {
+            self.cached_accounts.insert(data.pubkey);
+        }
+        self.remove_closed();
+    }
+
+    /// Stream the cached accounts by pubkey in addition to their program
+    pub fn stream_cached_accounts(&mut self, pubkeys: impl IntoIterator<Item = Pubkey>) {
+        self.cached_accounts.extend(pubkeys);
+        self.update_filters();
+    }
+
+    /// Remove the subscriptions whose client has unsubscribed and stop streaming their accounts
+    pub fn remove_closed(&mut self) {
+        let is_open = |sender: &broadcast::Sender<AccountData>| sender.receiver_count() > 0;
+        self.accounts.retain(|_, senders| {
+            senders.retain(is_open);
+            !senders.is_empty()
+        });
+        self.programs.retain(|_, subscribers| {
+            subscribers.retain(|(_, sender)| is_open(sender));
+            !subscribers.is_empty()
+        });
+        self.update_filters();
+    }
+
+    fn update_filters(&self) {
+        let mut filters = AccountStreamFilters::new();
+        if !self.cached_programs.is_empty() {
+            for commitment in [
+                Commitment::Processed,
+                Commitment::Confirmed,
+                Commitment::Finalized,
+            ] {
+                filters.insert(
+                    commitment,
+                    AccountStreamFilter {
+                        accounts: self.cached_accounts.clone(),
+                        programs: self
+                            .cached_programs
+                            .iter()
+                            .map(|program_id| (*program_id, vec![]))
+                            .collect(),
+                    },
+                );
+            }
+        }
+        for (commitment, pubkey) in self.accounts.keys() {
+            let filter: &mut AccountStreamFilter = filters.entry(*commitment).or_default();
+            filter.accounts.insert(*pubkey);
+        }
+        for ((commitment, program_id), subscribers) in &self.programs {
+            // the accounts of a cached program are all streamed
+            let filter: &mut AccountStreamFilter = filters.entry(*commitment).or_default();
+            filter
+                .programs
+                .entry(*program_id)
+                .or_insert_with(|| common_filters(subscribers));
+        }
+        self.filters_sender.send_if_modified(|current| {
+            if *current == filters {
+                return false;
+            }
+            *current = filters;
+            true
+        });
+    }
+}
+
+// filters shared by all the subscriptions of a program, none when they differ
+fn common_filters(subscribers: &ProgramSubscribers) -> Vec<RpcFilterType> {
+    let mut filters = subscribers.iter().map(|(filters, _)| filters);
+    let Some(first) = filters.next() else {
+        return vec![];
+    };
+    if filters.all(|filters| filters == first) {
+        first.clone()
+    } else {
+        vec![]
+    }
+}
+
+/// `accountNotification` of an account update
+pub fn account_notification(
+    data: &AccountData,
+    encoding: UiAccountEncoding,
+    data_slice: Option<UiDataSliceConfig>,
+) -> RpcResponse<UiAccount> {
+    RpcResponse {
+        context: RpcResponseContext::new(data.updated_slot),
+        // no additional data, token accounts requested as jsonParsed are encoded in base64
+        value: UiAccount::encode(
+            &data.pubkey,
+            data.account.as_ref(),
+            encoding,
+            None,
+            data_slice,
+        ),
+    }
+}
+
+/// `programNotification` of an account update, none if the account doesn't match the filters
+pub fn program_notification(
+    data: &AccountData,
+    filters: &[RpcFilterType],
+    encoding: UiAccountEncoding,
+    data_slice: Option<UiDataSliceConfig>,
+) -> Option<RpcResponse<RpcKeyedAccount>> {
+    if !matches_filters(data, filters) {
+        return None;
+    }
+
+    let RpcResponse { context, value } = account_notification(data, encoding, data_slice);
+    Some(RpcResponse {
+        context,
+        value: RpcKeyedAccount {
+            pubkey: data.pubkey.to_string(),
+            account: value,
+        },
+    })
+}
+
+/// true when the account matches all the program accounts filters
+pub fn matches_filters(data: &AccountData, filters: &[RpcFilterType]) -> bool {
+    let account_data = &data.account.data;
+    filters.iter().all(|filter| match filter {
+        RpcFilterType::DataSize(size) => account_data.len() as u64 == *size,
+        RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(account_data),
+        RpcFilterType::TokenAccountState => {
+            filter.allows(&AccountSharedData::from(data.account.as_ref().clone()))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_rpc_client_api::filter::Memcmp;
+    use solana_sdk::account::Account;
+    use std::{collections::BTreeMap, sync::Arc};
+
+    fn account_data(pubkey: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountData {
+        AccountData {
+            pubkey,
+            account: Arc::new(Account {
+                lamports: 1,
+                data,
+                owner,
+                ..Default::default()
+            }),
+            updated_slot: 1,
+            write_version: 0,
+        }
+    }
+
+    #[test]
+    fn cached_accounts_are_streamed_by_pubkey() {
+        let (program_id, pubkey) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (filters_sender, filters) = tokio::sync::watch::channel(AccountStreamFilters::new());
+        let mut subscriptions =
+            AccountSubscriptions::new(filters_sender, BTreeSet::from([program_id]));
+        assert!(filters.borrow()[&Commitment::Processed].accounts.is_empty());
+
+        let message = AccountNotificationMessage {
+            data: account_data(pubkey, program_id, vec![]),
+            commitment: Commitment::Processed,
+        };
+        assert!(subscriptions.notify(&message));
+        subscriptions.update_streamed(&message.data);
+        assert!(!subscriptions.notify(&message));
+        for commitment in [
+            Commitment::Processed,
+            Commitment::Confirmed,
+            Commitment::Finalized,
+        ] {
+            let filter = &filters.borrow()[&commitment];
+            assert_eq!(filter.accounts, BTreeSet::from([pubkey]));
+            assert_eq!(filter.programs, BTreeMap::from([(program_id, vec![])]));
+        }
+
+        // closed accounts are still streamed by pubkey
+        let closed = AccountNotificationMessage {
+            data: account_data(pubkey, Pubkey::default(), vec![]),
+            commitment: Commitment::Processed,
+        };
+        assert!(!subscriptions.notify(&closed));
+    }
+
+    #[test]
+    fn program_accounts_filters() {
+        let mut data = vec![0; 165];
+        data[..4].copy_from_slice(&[1, 2, 3, 4]);
+        let account = account_data(Pubkey::new_unique(), Pubkey::new_unique(), data.clone());
+
+        assert!(matches_filters(&account, &[]));
+        assert!(matches_filters(
+            &account,
+            &[
+                RpcFilterType::DataSize(165),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(1, vec![2, 3])),
+            ]
+        ));
+        assert!(!matches_filters(&account, &[RpcFilterType::DataSize(164)]));
+        assert!(!matches_filters(
+            &account,
+            &[
+                RpcFilterType::DataSize(165),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(1, vec![3])),
+            ]
+        ));
+
+        // token account state byte
+        assert!(!matches_filters(
+            &account,
+            &[RpcFilterType::TokenAccountState]
+        ));
+        data[108] = 1;
+        let account = account_data(Pubkey::new_unique(), Pubkey::new_unique(), data);
+        assert!(matches_filters(
+            &account,
+            &[RpcFilterType::TokenAccountState]
+        ));
+    }
+
+    #[test]
+    fn closed_subscriptions_are_not_streamed() {
+        let (pubkey, program_id) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (filters_sender, filters) = tokio::sync::watch::channel(AccountStreamFilters::new());
+        let mut subscriptions = AccountSubscriptions::new(filters_sender, BTreeSet::new());
+        assert!(filters.borrow().is_empty());
+
+        let account_receiver = subscriptions.subscribe_account(pubkey, Commitment::Confirmed);
+        let program_receiver =
+            subscriptions.subscribe_program(program_id, vec![], Commitment::Processed);
+        assert_eq!(
+            filters.borrow()[&Commitment::Confirmed],
+            AccountStreamFilter {
+                accounts: BTreeSet::from([pubkey]),
+                programs: BTreeMap::new(),
+            }
+        );
+        assert_eq!(
+            filters.borrow()[&Commitment::Processed],
+            AccountStreamFilter {
+                accounts: BTreeSet::new(),
+                programs: BTreeMap::from([(program_id, vec![])]),
+            }
+        );
+
+        // the closed subscription is found when its account is notified
+        drop(account_receiver);
+        let message = AccountNotificationMessage {
+            data: account_data(pubkey, program_id, vec![]),
+            commitment: Commitment::Confirmed,
+        };
+        assert!(subscriptions.notify(&message));
+        subscriptions.remove_closed();
+        assert!(!filters.borrow().contains_key(&Commitment::Confirmed));
+        assert!(!subscriptions.notify(&message));
+
+        drop(program_receiver);
+        subscriptions.remove_closed();
+        assert!(filters.borrow().is_empty());
+    }
+
+    #[test]
+    fn shared_program_filters_are_streamed() {
+        let (program_id, cached_program_id) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (filters_sender, filters) = tokio::sync::watch::channel(AccountStreamFilters::new());
+        let mut subscriptions =
+            AccountSubscriptions::new(filters_sender, BTreeSet::from([cached_program_id]));
+        let token_filters = vec![
+            RpcFilterType::DataSize(165),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(32, vec![1; 32])),
+        ];
+        let program_filters =
+            || filters.borrow()[&Commitment::Confirmed].programs[&program_id].clone();
+
+        let first = subscriptions.subscribe_program(
+            program_id,
+            token_filters.clone(),
+            Commitment::Confirmed,
+        );
+        let _second = subscriptions.subscribe_program(
+            program_id,
+            token_filters.clone(),
+            Commitment::Confirmed,
+        );
+        assert_eq!(program_filters(), token_filters);
+
+        // all the accounts are streamed when the filters differ
+        let other = subscriptions.subscribe_program(
+            program_id,
+            vec![RpcFilterType::DataSize(82)],
+            Commitment::Confirmed,
+        );
+        assert_eq!(program_filters(), vec![]);
+
+        drop(other);
+        subscriptions.remove_closed();
+        assert_eq!(program_filters(), token_filters);
+        drop(first);
+        subscriptions.remove_closed();
+        assert_eq!(program_filters(), token_filters);
+
+        // the accounts of the cached programs are never filtered
+        let _cached = subscriptions.subscribe_program(
+            cached_program_id,
+            token_filters,
+            Commitment::Confirmed,
+        );
+        assert_eq!(
+            filters.borrow()[&Commitment::Confirmed].programs[&cached_program_id],
+            vec![]
+        );
+    }
+}
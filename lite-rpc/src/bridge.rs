@@ -1,46 +1,108 @@
 use crate::{
-    configs::{IsBlockHashValidConfig, SendTransactionConfig},
+    account_subscriptions::{account_notification, matches_filters, program_notification},
+    configs::{
+        IsBlockHashValidConfig, PrioritizationFeePercentilesConfig, RpcTransactionSubscribeConfig,
+        RpcTransactionSubscribeFilter, SendTransactionConfig,
+    },
     jsonrpsee_subscrption_handler_sink::JsonRpseeSubscriptionHandlerSink,
+    responses::{
+        RpcClusterNode, RpcPrioritizationFeePercentiles, RpcTransactionLifecycle,
+        RpcTransactionLifecycleUpdate, RpcUpcomingLeader,
+    },
     rpc::LiteRpcServer,
+    subscriptions::{block_notification, spawn_subscription, LogsFilter, SubscriptionStreams},
+    transaction_subscriptions::{
+        transaction_notifications, TransactionEncodingOptions, TransactionFilter,
+    },
+    DEFAULT_FANOUT_SIZE,
 };
+use solana_account_decoder::{UiAccount, UiAccountEncoding, UiDataSliceConfig, MAX_BASE58_BYTES};
 use solana_sdk::epoch_info::EpochInfo;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use solana_lite_rpc_services::{
-    transaction_service::TransactionService, tx_sender::TXS_IN_CHANNEL,
+    performance_samples::{PerformanceSamples, PERFORMANCE_SAMPLES_LIMIT},
+    transaction_service::TransactionService,
+    tx_sender::TXS_IN_CHANNEL,
 };
 
 use anyhow::Context;
-use jsonrpsee::{core::SubscriptionResult, server::ServerBuilder, PendingSubscriptionSink};
+use jsonrpsee::{
+    core::SubscriptionResult,
+    server::ServerBuilder,
+    types::{error::CallError, ErrorObject},
+    PendingSubscriptionSink,
+};
 use prometheus::{opts, register_int_counter, IntCounter};
 use solana_lite_rpc_core::{
-    stores::{block_information_store::BlockInformation, data_cache::DataCache, tx_store::TxProps},
+    stores::{
+        block_information_store::BlockInformation,
+        data_cache::DataCache,
+        prioritization_fees_store::{PrioritizationFeePercentiles, NB_SLOTS_PRIORITIZATION_FEES},
+        tx_store::TxProps,
+    },
+    structures::{
+        account_data::AccountData, leaderschedule::GetVoteAccountsConfig,
+        produced_block::ProducedTransaction,
+    },
+    traits::leaders_fetcher_interface::LeaderFetcherInterface,
+    types::{SubscptionHanderSink, VoteAccountsRequestSender},
     AnyhowJoinHandle,
 };
 use solana_lite_rpc_history::history::History;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::{
     config::{
-        RpcBlockConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter, RpcBlocksConfigWrapper,
-        RpcContextConfig, RpcEncodingConfigWrapper, RpcGetVoteAccountsConfig,
-        RpcLeaderScheduleConfig, RpcProgramAccountsConfig, RpcRequestAirdropConfig,
-        RpcSignatureStatusConfig, RpcSignatureSubscribeConfig, RpcSignaturesForAddressConfig,
+        RpcAccountInfoConfig, RpcBlockConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
+        RpcBlocksConfigWrapper, RpcContextConfig, RpcEncodingConfigWrapper,
+        RpcGetVoteAccountsConfig, RpcLeaderScheduleConfig, RpcProgramAccountsConfig,
+        RpcRequestAirdropConfig, RpcSignatureStatusConfig, RpcSignatureSubscribeConfig,
+        RpcSignaturesForAddressConfig, RpcSimulateTransactionConfig, RpcTransactionConfig,
         RpcTransactionLogsConfig, RpcTransactionLogsFilter,
     },
+    custom_error::{
+        JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE,
+        JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED,
+        JSON_RPC_SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
+        JSON_RPC_SERVER_ERROR_TRANSACTION_SIGNATURE_VERIFICATION_FAILURE,
+    },
+    filter::RpcFilterType,
+    request::{
+        RpcRequest, MAX_GET_CONFIRMED_BLOCKS_RANGE,
+        MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT, MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS,
+        MAX_GET_SLOT_LEADERS, MAX_MULTIPLE_ACCOUNTS,
+    },
     response::{
-        Response as RpcResponse, RpcBlockhash, RpcConfirmedTransactionStatusWithSignature,
-        RpcContactInfo, RpcPerfSample, RpcPrioritizationFee, RpcResponseContext, RpcVersionInfo,
+        OptionalContext, Response as RpcResponse, RpcBlockhash,
+        RpcConfirmedTransactionStatusWithSignature, RpcContactInfo, RpcKeyedAccount, RpcPerfSample,
+        RpcPrioritizationFee, RpcResponseContext, RpcSimulateTransactionResult, RpcVersionInfo,
         RpcVoteAccountStatus,
     },
 };
-use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot};
-use solana_transaction_status::{TransactionStatus, UiConfirmedBlock};
+use solana_sdk::{
+    clock::UnixTimestamp,
+    commitment_config::{CommitmentConfig, CommitmentLevel},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::Signature,
+    slot_history::Slot,
+    transaction::{TransactionError, VersionedTransaction, MAX_TX_ACCOUNT_LOCKS},
+    vote::state::MAX_LOCKOUT_HISTORY,
+};
+use solana_transaction_status::{
+    TransactionConfirmationStatus, TransactionDetails, TransactionStatus, UiConfirmedBlock,
+    UiTransactionEncoding,
+};
 use std::{str::FromStr, sync::Arc};
-use tokio::net::ToSocketAddrs;
+use tokio::{net::ToSocketAddrs, sync::oneshot};
 
 lazy_static::lazy_static! {
     static ref RPC_SEND_TX: IntCounter =
     register_int_counter!(opts!("literpc_rpc_send_tx", "RPC call send transaction")).unwrap();
+    static ref RPC_SEND_TX_PREFLIGHT_FAILURES: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_send_tx_preflight_failures", "Transactions rejected by the preflight simulation")).unwrap();
+    static ref RPC_GET_TRANSACTION_LIFECYCLES: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_transaction_lifecycles", "RPC call to get the lifecycle of sent transactions")).unwrap();
     static ref RPC_GET_LATEST_BLOCKHASH: IntCounter =
     register_int_counter!(opts!("literpc_rpc_get_latest_blockhash", "RPC call to get latest block hash")).unwrap();
     static ref RPC_IS_BLOCKHASH_VALID: IntCounter =
@@ -53,6 +115,32 @@ lazy_static::lazy_static! {
     register_int_counter!(opts!("literpc_rpc_airdrop", "RPC call to request airdrop")).unwrap();
     static ref RPC_SIGNATURE_SUBSCRIBE: IntCounter =
     register_int_counter!(opts!("literpc_rpc_signature_subscribe", "RPC call to subscribe to signature")).unwrap();
+    static ref RPC_BLOCK_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_block_subscribe", "RPC call to subscribe to blocks")).unwrap();
+    static ref RPC_TRANSACTION_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_transaction_subscribe", "RPC call to subscribe to transactions")).unwrap();
+    static ref RPC_TRANSACTION_LIFECYCLE_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_transaction_lifecycle_subscribe", "RPC call to subscribe to the lifecycle of sent transactions")).unwrap();
+    static ref RPC_LOGS_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_logs_subscribe", "RPC call to subscribe to transaction logs")).unwrap();
+    static ref RPC_ACCOUNT_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_account_subscribe", "RPC call to subscribe to an account")).unwrap();
+    static ref RPC_PROGRAM_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_program_subscribe", "RPC call to subscribe to program accounts")).unwrap();
+    static ref RPC_SLOT_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_slot_subscribe", "RPC call to subscribe to slots")).unwrap();
+    static ref RPC_SLOTS_UPDATES_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_slots_updates_subscribe", "RPC call to subscribe to slot updates")).unwrap();
+    static ref RPC_GET_ACCOUNT_INFO: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_account_info", "RPC call to get account info")).unwrap();
+    static ref RPC_GET_MULTIPLE_ACCOUNTS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_multiple_accounts", "RPC call to get multiple accounts")).unwrap();
+    static ref RPC_GET_BALANCE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_balance", "RPC call to get balance")).unwrap();
+    static ref RPC_GET_PROGRAM_ACCOUNTS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_program_accounts", "RPC call to get program accounts")).unwrap();
+    static ref ACCOUNT_CACHE_MISSES: IntCounter =
+    register_int_counter!(opts!("literpc_account_cache_misses", "Account requests forwarded to the rpc")).unwrap();
 }
 
 /// A bridge between clients and tpu
@@ -62,20 +150,39 @@ pub struct LiteBridge {
     rpc_client: Arc<RpcClient>,
     transaction_service: TransactionService,
     history: History,
+    performance_samples: PerformanceSamples,
+    // vote accounts computed from geyser, the rpc is used when not set
+    vote_accounts_sender: Option<VoteAccountsRequestSender>,
+    subscription_streams: SubscriptionStreams,
+    // same leaders as the tpu service, falls back to the rpc when calculated from geyser
+    leader_fetcher: Arc<dyn LeaderFetcherInterface>,
+    // the logs are part of the transaction meta
+    keep_transaction_meta: bool,
 }
 
 impl LiteBridge {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         rpc_client: Arc<RpcClient>,
         data_cache: DataCache,
         transaction_service: TransactionService,
         history: History,
+        performance_samples: PerformanceSamples,
+        vote_accounts_sender: Option<VoteAccountsRequestSender>,
+        subscription_streams: SubscriptionStreams,
+        leader_fetcher: Arc<dyn LeaderFetcherInterface>,
+        keep_transaction_meta: bool,
     ) -> Self {
         Self {
             rpc_client,
             data_cache,
             transaction_service,
             history,
+            performance_samples,
+            vote_accounts_sender,
+            subscription_streams,
+            leader_fetcher,
+            keep_transaction_meta,
         }
     }
 
@@ -120,6 +227,276 @@ impl LiteBridge {
             },
         }
     }
+
+    /// Slot of the account cache at the commitment, none when the min context slot is not reached
+    async fn accounts_context_slot(
+        &self,
+        commitment: CommitmentConfig,
+        min_context_slot: Option<Slot>,
+    ) -> Option<Slot> {
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment)
+            .await;
+        min_context_slot
+            .map_or(true, |min_context_slot| slot >= min_context_slot)
+            .then_some(slot)
+    }
+
+    /// Status of a transaction evicted from the tx store, searched in the block history
+    async fn history_signature_status(&self, signature: &str) -> Option<TransactionStatus> {
+        let config = RpcTransactionConfig {
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        };
+        let ProducedTransaction {
+            transaction,
+            slot,
+            commitment_config,
+            ..
+        } = self
+            .history
+            .block_storage
+            .get_transaction(signature, config)
+            .await
+            .ok()?;
+        Some(TransactionStatus {
+            slot,
+            confirmations: None,
+            status: transaction.err.clone().map_or(Ok(()), Err),
+            err: transaction.err,
+            confirmation_status: Some(if commitment_config.is_finalized() {
+                TransactionConfirmationStatus::Finalized
+            } else {
+                TransactionConfirmationStatus::Confirmed
+            }),
+        })
+    }
+
+    /// Simulate the transaction on the rpc, the simulation failure is returned with its logs
+    /// like the preflight of the rpc
+    async fn preflight(
+        &self,
+        transaction: &VersionedTransaction,
+        commitment: CommitmentLevel,
+        min_context_slot: Option<Slot>,
+    ) -> crate::rpc::Result<()> {
+        let RpcSimulateTransactionResult {
+            err,
+            logs,
+            units_consumed,
+            return_data,
+            ..
+        } = self
+            .rpc_client
+            .simulate_transaction_with_config(
+                transaction,
+                RpcSimulateTransactionConfig {
+                    // signatures are verified by lite-rpc
+                    sig_verify: false,
+                    commitment: Some(CommitmentConfig { commitment }),
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    min_context_slot,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?
+            .value;
+
+        let Some(err) = err else {
+            return Ok(());
+        };
+        RPC_SEND_TX_PREFLIGHT_FAILURES.inc();
+        Err(jsonrpsee::core::Error::Call(CallError::Custom(
+            ErrorObject::owned(
+                JSON_RPC_SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE as i32,
+                format!("Transaction simulation failed: {err}"),
+                Some(RpcSimulateTransactionResult {
+                    err: Some(err),
+                    logs,
+                    accounts: None,
+                    units_consumed,
+                    return_data,
+                }),
+            ),
+        )))
+    }
+
+    /// Forward an account request missed by the account cache
+    async fn forward_to_rpc<T: serde::de::DeserializeOwned>(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> crate::rpc::Result<T> {
+        ACCOUNT_CACHE_MISSES.inc();
+        self.rpc_client
+            .send(request, params)
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))
+    }
+}
+
+fn check_is_at_least_confirmed(commitment: CommitmentConfig) -> crate::rpc::Result<()> {
+    if commitment.is_processed() {
+        return Err(jsonrpsee::core::Error::Custom(
+            "Method does not support commitment below `confirmed`".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn check_min_context_slot(
+    min_context_slot: Option<Slot>,
+    context_slot: Slot,
+) -> crate::rpc::Result<()> {
+    match min_context_slot {
+        Some(min_context_slot) if context_slot < min_context_slot => Err(
+            jsonrpsee::core::Error::Call(CallError::Custom(ErrorObject::owned(
+                JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED as i32,
+                "Minimum context slot has not been reached",
+                Some(serde_json::json!({ "contextSlot": context_slot })),
+            ))),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Status with its confirmations at the latest confirmed and finalized slots.
+///
+/// Rooted transactions have no confirmations, the other ones count the slots confirmed on top of
+/// theirs up to the vote lockout history. Only confirmed transactions are rooted with the finalized
+/// slot, the processed ones may be on a fork.
+fn status_with_confirmations(
+    status: TransactionStatus,
+    confirmed_slot: Slot,
+    finalized_slot: Slot,
+) -> TransactionStatus {
+    let is_finalized = match status.confirmation_status {
+        Some(TransactionConfirmationStatus::Finalized) => true,
+        Some(TransactionConfirmationStatus::Confirmed) => status.slot <= finalized_slot,
+        _ => false,
+    };
+    if is_finalized {
+        return TransactionStatus {
+            confirmations: None,
+            confirmation_status: Some(TransactionConfirmationStatus::Finalized),
+            ..status
+        };
+    }
+    let confirmations = match status.confirmation_status {
+        Some(TransactionConfirmationStatus::Processed) => 0,
+        _ => ((confirmed_slot.saturating_sub(status.slot) + 1) as usize).min(MAX_LOCKOUT_HISTORY),
+    };
+    TransactionStatus {
+        confirmations: Some(confirmations),
+        ..status
+    }
+}
+
+/// Checks done by the rpc before any simulation: size, sanitization, account locks and signatures
+fn sanitize_transaction(raw_tx: &[u8]) -> crate::rpc::Result<VersionedTransaction> {
+    if raw_tx.len() > PACKET_DATA_SIZE {
+        return Err(jsonrpsee::core::Error::Custom(format!(
+            "transaction too large: {} bytes (max: {PACKET_DATA_SIZE} bytes)",
+            raw_tx.len()
+        )));
+    }
+    let invalid_transaction =
+        |err: String| jsonrpsee::core::Error::Custom(format!("invalid transaction: {err}"));
+    let transaction = bincode::deserialize::<VersionedTransaction>(raw_tx)
+        .map_err(|err| invalid_transaction(err.to_string()))?;
+    transaction
+        .sanitize()
+        .map_err(|err| invalid_transaction(TransactionError::from(err).to_string()))?;
+
+    // accounts of the address lookup tables are checked by the simulation
+    let account_keys = transaction.message.static_account_keys();
+    if account_keys.len() > MAX_TX_ACCOUNT_LOCKS {
+        return Err(invalid_transaction(
+            TransactionError::TooManyAccountLocks.to_string(),
+        ));
+    }
+    if account_keys.iter().collect::<HashSet<_>>().len() != account_keys.len() {
+        return Err(invalid_transaction(
+            TransactionError::AccountLoadedTwice.to_string(),
+        ));
+    }
+
+    if transaction.verify_with_results().contains(&false) {
+        return Err(jsonrpsee::core::Error::Call(CallError::Custom(
+            ErrorObject::owned(
+                JSON_RPC_SERVER_ERROR_TRANSACTION_SIGNATURE_VERIFICATION_FAILURE as i32,
+                "Transaction signature verification failure",
+                None::<()>,
+            ),
+        )));
+    }
+    Ok(transaction)
+}
+
+// accounts of the prioritization fee methods, limited like the account locks of a transaction
+fn parse_fee_accounts(pubkey_strs: Vec<String>) -> crate::rpc::Result<Vec<Pubkey>> {
+    if pubkey_strs.len() > MAX_TX_ACCOUNT_LOCKS {
+        return Err(jsonrpsee::core::Error::Custom(format!(
+            "Too many inputs provided; max {MAX_TX_ACCOUNT_LOCKS}"
+        )));
+    }
+    pubkey_strs
+        .iter()
+        .map(|pubkey| Pubkey::from_str(pubkey))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))
+}
+
+// filters of the program accounts, memcmp bytes are decoded once when subscribing
+fn verify_filters(filters: Option<Vec<RpcFilterType>>) -> crate::rpc::Result<Vec<RpcFilterType>> {
+    let mut filters = filters.unwrap_or_default();
+    for filter in &mut filters {
+        filter
+            .verify()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err:?}")))?;
+        if let RpcFilterType::Memcmp(memcmp) = filter {
+            memcmp
+                .convert_to_raw_bytes()
+                .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err:?}")))?;
+        }
+    }
+    Ok(filters)
+}
+
+// the rpc parses the accounts requested as jsonParsed and rejects the base58 encoding
+// of large accounts, these accounts are left to it
+fn is_encodable_from_cache(
+    data: &AccountData,
+    encoding: UiAccountEncoding,
+    data_slice: Option<UiDataSliceConfig>,
+) -> bool {
+    let data_len = data.account.data.len();
+    let sliced_len = data_slice.map_or(data_len, |UiDataSliceConfig { offset, length }| {
+        data_len.saturating_sub(offset).min(length)
+    });
+    match encoding {
+        UiAccountEncoding::JsonParsed => false,
+        UiAccountEncoding::Binary | UiAccountEncoding::Base58 => sliced_len <= MAX_BASE58_BYTES,
+        UiAccountEncoding::Base64 | UiAccountEncoding::Base64Zstd => true,
+    }
+}
+
+// accounts without lamports don't exist
+fn encode_cached_account(
+    data: &AccountData,
+    encoding: UiAccountEncoding,
+    data_slice: Option<UiDataSliceConfig>,
+) -> Option<UiAccount> {
+    (data.account.lamports > 0).then(|| account_notification(data, encoding, data_slice).value)
+}
+
+fn accounts_not_streamed() -> jsonrpsee::core::Error {
+    jsonrpsee::core::Error::Custom(
+        "Account subscriptions are only supported with a geyser cluster endpoint".to_string(),
+    )
 }
 
 #[jsonrpsee::core::async_trait]
@@ -130,59 +507,254 @@ impl LiteRpcServer for LiteBridge {
         config: Option<RpcEncodingConfigWrapper<RpcBlockConfig>>,
     ) -> crate::rpc::Result<Option<UiConfirmedBlock>> {
         let config = config.map_or(RpcBlockConfig::default(), |x| x.convert_to_current());
-        let block = self.history.block_storage.get(slot, config).await;
-        if block.is_ok() {
-            // TO DO Convert to UIConfirmed Block
-            Err(jsonrpsee::core::Error::HttpNotImplemented)
-        } else {
-            Ok(None)
+        let Ok(block) = self.history.block_storage.get(slot, config).await else {
+            return Ok(None);
+        };
+
+        match block.to_ui_block(&config) {
+            Ok(ui_block) => Ok(Some(ui_block)),
+            Err(err) => Err(jsonrpsee::core::Error::Custom(format!(
+                "error converting block {slot}: {err}"
+            ))),
         }
     }
 
     async fn get_blocks(
         &self,
-        _start_slot: Slot,
-        _config: Option<RpcBlocksConfigWrapper>,
-        _commitment: Option<CommitmentConfig>,
+        start_slot: Slot,
+        config: Option<RpcBlocksConfigWrapper>,
+        commitment: Option<CommitmentConfig>,
     ) -> crate::rpc::Result<Vec<Slot>> {
-        todo!()
+        let (end_slot, config_commitment) = config.map(|x| x.unzip()).unwrap_or_default();
+        let commitment_config = commitment.or(config_commitment).unwrap_or_default();
+        check_is_at_least_confirmed(commitment_config)?;
+
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await;
+        let end_slot = end_slot
+            .unwrap_or_else(|| start_slot.saturating_add(MAX_GET_CONFIRMED_BLOCKS_RANGE))
+            .min(slot);
+        if end_slot < start_slot {
+            return Ok(vec![]);
+        }
+        if end_slot - start_slot > MAX_GET_CONFIRMED_BLOCKS_RANGE {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Slot range too large; max {MAX_GET_CONFIRMED_BLOCKS_RANGE}"
+            )));
+        }
+
+        self.history
+            .block_storage
+            .get_slots_in_range(start_slot..end_slot + 1, commitment_config)
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("error getting blocks: {err}")))
+    }
+
+    async fn get_blocks_with_limit(
+        &self,
+        start_slot: Slot,
+        limit: usize,
+        config: Option<RpcContextConfig>,
+    ) -> crate::rpc::Result<Vec<Slot>> {
+        let commitment_config = config
+            .map(|config| config.commitment.unwrap_or_default())
+            .unwrap_or_default();
+        check_is_at_least_confirmed(commitment_config)?;
+        if limit as u64 > MAX_GET_CONFIRMED_BLOCKS_RANGE {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Limit too large; max {MAX_GET_CONFIRMED_BLOCKS_RANGE}"
+            )));
+        }
+
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await;
+        // skipped slots are unknown in advance, so the range is extended until enough
+        // blocks are found, doubling the size of the next range to bound the number of queries
+        let mut slots = vec![];
+        let mut from_slot = start_slot;
+        let mut range_size = (limit as u64).max(1);
+        while slots.len() < limit && from_slot <= slot {
+            let to_slot = from_slot.saturating_add(range_size).min(slot + 1);
+            let range_slots = self
+                .history
+                .block_storage
+                .get_slots_in_range(from_slot..to_slot, commitment_config)
+                .await
+                .map_err(|err| {
+                    jsonrpsee::core::Error::Custom(format!("error getting blocks: {err}"))
+                })?;
+            slots.extend(range_slots);
+            from_slot = to_slot;
+            range_size = range_size
+                .saturating_mul(2)
+                .min(MAX_GET_CONFIRMED_BLOCKS_RANGE);
+        }
+        slots.truncate(limit);
+        Ok(slots)
     }
 
     async fn get_signatures_for_address(
         &self,
-        _address: String,
-        _config: Option<RpcSignaturesForAddressConfig>,
+        address: String,
+        config: Option<RpcSignaturesForAddressConfig>,
     ) -> crate::rpc::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
-        todo!()
+        let address = Pubkey::from_str(&address)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+        check_is_at_least_confirmed(commitment_config)?;
+        let limit = config
+            .limit
+            .unwrap_or(MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT);
+        if limit == 0 || limit > MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Invalid limit; max {MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT}"
+            )));
+        }
+
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await;
+        check_min_context_slot(config.min_context_slot, slot)?;
+
+        self.history
+            .block_storage
+            .get_signatures_for_address(
+                &address,
+                RpcSignaturesForAddressConfig {
+                    limit: Some(limit),
+                    commitment: Some(commitment_config),
+                    ..config
+                },
+            )
+            .await
+            .map_err(|err| {
+                jsonrpsee::core::Error::Custom(format!(
+                    "error getting signatures for address {address}: {err}"
+                ))
+            })
+    }
+
+    async fn get_transaction(
+        &self,
+        signature_str: String,
+        config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+    ) -> crate::rpc::Result<Option<serde_json::Value>> {
+        let config = config.map_or(RpcTransactionConfig::default(), |x| x.convert_to_current());
+        let commitment = config.commitment.unwrap_or_default();
+        check_is_at_least_confirmed(commitment)?;
+
+        let Ok(transaction) = self
+            .history
+            .block_storage
+            .get_transaction(&signature_str, config)
+            .await
+        else {
+            return Ok(None);
+        };
+        if commitment.is_finalized() && !transaction.commitment_config.is_finalized() {
+            return Ok(None);
+        }
+
+        transaction
+            .to_encoded_transaction(&config)
+            .and_then(|encoded| Ok(serde_json::to_value(encoded)?))
+            .map(Some)
+            .map_err(|err| {
+                jsonrpsee::core::Error::Custom(format!(
+                    "error converting transaction {signature_str}: {err}"
+                ))
+            })
     }
 
     async fn get_cluster_nodes(&self) -> crate::rpc::Result<Vec<RpcContactInfo>> {
-        todo!()
+        Ok(self
+            .data_cache
+            .cluster_info
+            .cluster_nodes
+            .iter()
+            .map(|node| node.value().as_ref().clone())
+            .collect())
+    }
+
+    async fn get_cluster_node(&self, pubkey: String) -> crate::rpc::Result<RpcClusterNode> {
+        let pubkey = Pubkey::from_str(&pubkey)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let cluster_info = &self.data_cache.cluster_info;
+        Ok(RpcClusterNode {
+            contact_info: cluster_info
+                .cluster_nodes
+                .get(&pubkey)
+                .map(|node| node.value().as_ref().clone()),
+            last_update: cluster_info.last_update(),
+        })
     }
 
     async fn get_slot(&self, config: Option<RpcContextConfig>) -> crate::rpc::Result<Slot> {
-        let commitment_config = config
-            .map(|config| config.commitment.unwrap_or_default())
-            .unwrap_or_default();
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
 
         let BlockInformation { slot, .. } = self
             .data_cache
             .block_information_store
             .get_latest_block(commitment_config)
             .await;
+        check_min_context_slot(config.min_context_slot, slot)?;
         Ok(slot)
     }
 
-    async fn get_block_height(&self, _config: Option<RpcContextConfig>) -> crate::rpc::Result<u64> {
-        todo!()
+    async fn get_block_height(&self, config: Option<RpcContextConfig>) -> crate::rpc::Result<u64> {
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+
+        let BlockInformation {
+            slot, block_height, ..
+        } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await;
+        check_min_context_slot(config.min_context_slot, slot)?;
+        Ok(block_height)
     }
 
-    async fn get_block_time(&self, _block: u64) -> crate::rpc::Result<u64> {
-        todo!()
+    async fn get_block_time(&self, block: u64) -> crate::rpc::Result<Option<UnixTimestamp>> {
+        let config = RpcBlockConfig {
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        };
+        let Ok(block) = self.history.block_storage.get(block, config).await else {
+            return Err(jsonrpsee::core::Error::Call(CallError::Custom(
+                ErrorObject::owned(
+                    JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE as i32,
+                    format!("Block not available for slot {block}"),
+                    None::<()>,
+                ),
+            )));
+        };
+        Ok((block.block_time != 0).then_some(block.block_time as UnixTimestamp))
     }
 
     async fn get_first_available_block(&self) -> crate::rpc::Result<u64> {
-        todo!()
+        let range = self.history.block_storage.get_slot_range().await;
+        if range.is_empty() {
+            return Err(jsonrpsee::core::Error::Call(CallError::Custom(
+                ErrorObject::owned(
+                    JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE as i32,
+                    "No block available yet".to_string(),
+                    None::<()>,
+                ),
+            )));
+        }
+        Ok(range.start)
     }
 
     async fn get_latest_blockhash(
@@ -269,31 +841,67 @@ impl LiteRpcServer for LiteBridge {
 
     async fn get_recent_performance_samples(
         &self,
-        _limit: Option<usize>,
+        limit: Option<usize>,
     ) -> crate::rpc::Result<Vec<RpcPerfSample>> {
-        todo!()
+        let limit = limit.unwrap_or(PERFORMANCE_SAMPLES_LIMIT);
+        if limit > PERFORMANCE_SAMPLES_LIMIT {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Invalid limit; max {PERFORMANCE_SAMPLES_LIMIT}"
+            )));
+        }
+
+        Ok(self.performance_samples.get_recent_samples(limit).await)
     }
 
     async fn get_signature_statuses(
         &self,
         sigs: Vec<String>,
-        _config: Option<RpcSignatureStatusConfig>,
+        config: Option<RpcSignatureStatusConfig>,
     ) -> crate::rpc::Result<RpcResponse<Vec<Option<TransactionStatus>>>> {
         RPC_GET_SIGNATURE_STATUSES.inc();
+        if sigs.len() > MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Too many inputs provided; max {MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS}"
+            )));
+        }
+        for sig in &sigs {
+            Signature::from_str(sig)
+                .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        }
+        let search_transaction_history = config.is_some_and(|x| x.search_transaction_history);
 
-        let sig_statuses = sigs
-            .iter()
-            .map(|sig| self.data_cache.txs.get(sig).and_then(|v| v.status))
-            .collect();
+        let block_information_store = &self.data_cache.block_information_store;
+        let confirmed_slot = block_information_store
+            .get_latest_block(CommitmentConfig::confirmed())
+            .await
+            .slot;
+        let finalized_slot = block_information_store
+            .get_latest_block(CommitmentConfig::finalized())
+            .await
+            .slot;
+
+        let mut sig_statuses = Vec::with_capacity(sigs.len());
+        for sig in &sigs {
+            let mut status = self.data_cache.txs.get(sig).and_then(|v| v.status);
+            // the tx store only keeps the transactions whose blockhash is still valid
+            if status.is_none() && search_transaction_history {
+                status = self.history_signature_status(sig).await;
+            }
+            sig_statuses.push(
+                status.map(|status| {
+                    status_with_confirmations(status, confirmed_slot, finalized_slot)
+                }),
+            );
+        }
 
         Ok(RpcResponse {
             context: RpcResponseContext {
+                // processed slot like the rpc, statuses are at least confirmed though
                 slot: self
                     .data_cache
-                    .block_information_store
-                    .get_latest_block_info(CommitmentConfig::finalized())
-                    .await
-                    .slot,
+                    .slot_cache
+                    .get_current_slot()
+                    .max(confirmed_slot),
                 api_version: None,
             },
             value: sig_statuses,
@@ -302,9 +910,251 @@ impl LiteRpcServer for LiteBridge {
 
     async fn get_recent_prioritization_fees(
         &self,
-        _pubkey_strs: Option<Vec<String>>,
+        pubkey_strs: Option<Vec<String>>,
     ) -> crate::rpc::Result<Vec<RpcPrioritizationFee>> {
-        todo!()
+        let accounts = parse_fee_accounts(pubkey_strs.unwrap_or_default())?;
+
+        Ok(self
+            .data_cache
+            .prioritization_fees
+            .get_prioritization_fees(&accounts)
+            .await)
+    }
+
+    async fn get_prioritization_fee_percentiles(
+        &self,
+        config: Option<PrioritizationFeePercentilesConfig>,
+    ) -> crate::rpc::Result<RpcPrioritizationFeePercentiles> {
+        let PrioritizationFeePercentilesConfig {
+            account_keys,
+            slots,
+        } = config.unwrap_or_default();
+        let accounts = parse_fee_accounts(account_keys.unwrap_or_default())?;
+        let nb_slots = slots.unwrap_or(NB_SLOTS_PRIORITIZATION_FEES);
+        if nb_slots == 0 || nb_slots > NB_SLOTS_PRIORITIZATION_FEES {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Invalid slots; must be between 1 and {NB_SLOTS_PRIORITIZATION_FEES}"
+            )));
+        }
+
+        let PrioritizationFeePercentiles {
+            first_slot,
+            last_slot,
+            nb_transactions,
+            fees,
+        } = self
+            .data_cache
+            .prioritization_fees
+            .get_fee_percentiles(&accounts, nb_slots, &[25, 50, 75, 90, 99])
+            .await;
+        let fee = |index: usize| fees.get(index).copied().unwrap_or_default();
+
+        Ok(RpcPrioritizationFeePercentiles {
+            first_slot,
+            last_slot,
+            nb_transactions,
+            p25: fee(0),
+            p50: fee(1),
+            p75: fee(2),
+            p90: fee(3),
+            p99: fee(4),
+        })
+    }
+
+    async fn get_account_info(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Option<UiAccount>>> {
+        RPC_GET_ACCOUNT_INFO.inc();
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let RpcAccountInfoConfig {
+            encoding,
+            data_slice,
+            commitment,
+            min_context_slot,
+        } = config.clone().unwrap_or_default();
+        let commitment = commitment.unwrap_or_default();
+        let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
+
+        let cached = self
+            .data_cache
+            .accounts
+            .get_account(&pubkey, commitment.into())
+            .filter(|data| is_encodable_from_cache(data, encoding, data_slice));
+        let context_slot = self
+            .accounts_context_slot(commitment, min_context_slot)
+            .await;
+        if let (Some(data), Some(slot)) = (cached, context_slot) {
+            return Ok(RpcResponse {
+                context: RpcResponseContext::new(slot),
+                value: encode_cached_account(&data, encoding, data_slice),
+            });
+        }
+
+        self.forward_to_rpc(
+            RpcRequest::GetAccountInfo,
+            serde_json::json!([pubkey_str, config]),
+        )
+        .await
+    }
+
+    async fn get_multiple_accounts(
+        &self,
+        pubkey_strs: Vec<String>,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Vec<Option<UiAccount>>>> {
+        RPC_GET_MULTIPLE_ACCOUNTS.inc();
+        if pubkey_strs.len() > MAX_MULTIPLE_ACCOUNTS {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Too many inputs provided; max {MAX_MULTIPLE_ACCOUNTS}"
+            )));
+        }
+        let pubkeys = pubkey_strs
+            .iter()
+            .map(|pubkey| Pubkey::from_str(pubkey))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let RpcAccountInfoConfig {
+            encoding,
+            data_slice,
+            commitment,
+            min_context_slot,
+        } = config.clone().unwrap_or_default();
+        let commitment = commitment.unwrap_or_default();
+        let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
+
+        // served from the cache only when all the accounts are cached
+        let cached = pubkeys
+            .iter()
+            .map(|pubkey| {
+                self.data_cache
+                    .accounts
+                    .get_account(pubkey, commitment.into())
+                    .filter(|data| is_encodable_from_cache(data, encoding, data_slice))
+            })
+            .collect::<Option<Vec<_>>>();
+        let context_slot = self
+            .accounts_context_slot(commitment, min_context_slot)
+            .await;
+        if let (Some(cached), Some(slot)) = (cached, context_slot) {
+            return Ok(RpcResponse {
+                context: RpcResponseContext::new(slot),
+                value: cached
+                    .iter()
+                    .map(|data| encode_cached_account(data, encoding, data_slice))
+                    .collect(),
+            });
+        }
+
+        self.forward_to_rpc(
+            RpcRequest::GetMultipleAccounts,
+            serde_json::json!([pubkey_strs, config]),
+        )
+        .await
+    }
+
+    async fn get_balance(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcContextConfig>,
+    ) -> crate::rpc::Result<RpcResponse<u64>> {
+        RPC_GET_BALANCE.inc();
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let RpcContextConfig {
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+        let commitment = commitment.unwrap_or_default();
+
+        let cached = self
+            .data_cache
+            .accounts
+            .get_account(&pubkey, commitment.into());
+        let context_slot = self
+            .accounts_context_slot(commitment, min_context_slot)
+            .await;
+        if let (Some(data), Some(slot)) = (cached, context_slot) {
+            return Ok(RpcResponse {
+                context: RpcResponseContext::new(slot),
+                value: data.account.lamports,
+            });
+        }
+
+        self.forward_to_rpc(
+            RpcRequest::GetBalance,
+            serde_json::json!([pubkey_str, config]),
+        )
+        .await
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id_str: String,
+        config: Option<RpcProgramAccountsConfig>,
+    ) -> crate::rpc::Result<OptionalContext<Vec<RpcKeyedAccount>>> {
+        RPC_GET_PROGRAM_ACCOUNTS.inc();
+        let program_id = Pubkey::from_str(&program_id_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let RpcProgramAccountsConfig {
+            filters,
+            account_config,
+            with_context,
+        } = config.clone().unwrap_or_default();
+        let filters = verify_filters(filters)?;
+        let RpcAccountInfoConfig {
+            encoding,
+            data_slice,
+            commitment,
+            min_context_slot,
+        } = account_config;
+        let commitment = commitment.unwrap_or_default();
+        let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
+
+        // the program accounts are cached once all of them have been loaded
+        let cached = self
+            .data_cache
+            .accounts
+            .get_program_accounts(&program_id, commitment.into())
+            .map(|accounts| {
+                accounts
+                    .into_iter()
+                    .filter(|data| matches_filters(data, &filters))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|accounts| {
+                accounts
+                    .iter()
+                    .all(|data| is_encodable_from_cache(data, encoding, data_slice))
+            });
+        let context_slot = self
+            .accounts_context_slot(commitment, min_context_slot)
+            .await;
+        if let (Some(cached), Some(slot)) = (cached, context_slot) {
+            let accounts = cached
+                .iter()
+                .map(|data| RpcKeyedAccount {
+                    pubkey: data.pubkey.to_string(),
+                    account: account_notification(data, encoding, data_slice).value,
+                })
+                .collect();
+            return Ok(if with_context.unwrap_or_default() {
+                OptionalContext::Context(RpcResponse {
+                    context: RpcResponseContext::new(slot),
+                    value: accounts,
+                })
+            } else {
+                OptionalContext::NoContext(accounts)
+            });
+        }
+
+        self.forward_to_rpc(
+            RpcRequest::GetProgramAccounts,
+            serde_json::json!([program_id_str, config]),
+        )
+        .await
     }
 
     async fn send_transaction(
@@ -315,8 +1165,11 @@ impl LiteRpcServer for LiteBridge {
         RPC_SEND_TX.inc();
 
         let SendTransactionConfig {
+            skip_preflight,
+            preflight_commitment,
             encoding,
             max_retries,
+            min_context_slot,
         } = send_transaction_config.unwrap_or_default();
 
         let raw_tx = match encoding.decode(tx) {
@@ -325,6 +1178,27 @@ impl LiteRpcServer for LiteBridge {
                 return Err(jsonrpsee::core::Error::Custom(err.to_string()));
             }
         };
+        let transaction = sanitize_transaction(&raw_tx)?;
+        let preflight_commitment = preflight_commitment.unwrap_or_default();
+
+        let context_commitment = if skip_preflight {
+            CommitmentConfig::processed()
+        } else {
+            CommitmentConfig {
+                commitment: preflight_commitment,
+            }
+        };
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(context_commitment)
+            .await;
+        check_min_context_slot(min_context_slot, slot)?;
+
+        if !skip_preflight {
+            self.preflight(&transaction, preflight_commitment, min_context_slot)
+                .await?;
+        }
 
         match self
             .transaction_service
@@ -340,6 +1214,27 @@ impl LiteRpcServer for LiteBridge {
         }
     }
 
+    async fn get_transaction_lifecycles(
+        &self,
+        signature_strs: Vec<String>,
+    ) -> crate::rpc::Result<Vec<Option<RpcTransactionLifecycle>>> {
+        RPC_GET_TRANSACTION_LIFECYCLES.inc();
+        if signature_strs.len() > MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Too many inputs provided; max {MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS}"
+            )));
+        }
+        for signature in &signature_strs {
+            Signature::from_str(signature)
+                .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        }
+
+        Ok(signature_strs
+            .iter()
+            .map(|signature| self.data_cache.tx_lifecycles.get(signature).map(Into::into))
+            .collect())
+    }
+
     fn get_version(&self) -> crate::rpc::Result<RpcVersionInfo> {
         RPC_GET_VERSION.inc();
 
@@ -396,36 +1291,249 @@ impl LiteRpcServer for LiteBridge {
 
     async fn program_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
-        _pubkey_str: String,
-        _config: Option<RpcProgramAccountsConfig>,
+        pending: PendingSubscriptionSink,
+        pubkey_str: String,
+        config: Option<RpcProgramAccountsConfig>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_PROGRAM_SUBSCRIBE.inc();
+        let config = config.unwrap_or_default();
+        let RpcAccountInfoConfig {
+            encoding,
+            data_slice,
+            commitment,
+            ..
+        } = config.account_config;
+        let params = Pubkey::from_str(&pubkey_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))
+            .and_then(|program_id| Ok((program_id, verify_filters(config.filters)?)));
+        let (program_id, filters) = match params {
+            Ok(params) => params,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            }
+        };
+        let Some(accounts) = self
+            .subscription_streams
+            .subscribe_program(
+                program_id,
+                filters.clone(),
+                commitment.unwrap_or_default().into(),
+            )
+            .await
+        else {
+            pending.reject(accounts_not_streamed()).await;
+            return Ok(());
+        };
+
+        let sink = pending.accept().await?;
+        let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
+        spawn_subscription(sink, accounts, move |data| {
+            program_notification(&data, &filters, encoding, data_slice)
+        });
+        Ok(())
     }
 
-    async fn slot_subscribe(&self, _pending: PendingSubscriptionSink) -> SubscriptionResult {
-        todo!()
+    async fn account_subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> SubscriptionResult {
+        RPC_ACCOUNT_SUBSCRIBE.inc();
+        let RpcAccountInfoConfig {
+            encoding,
+            data_slice,
+            commitment,
+            ..
+        } = config.unwrap_or_default();
+        let pubkey = match Pubkey::from_str(&pubkey_str) {
+            Ok(pubkey) => pubkey,
+            Err(err) => {
+                pending
+                    .reject(jsonrpsee::core::Error::Custom(format!(
+                        "Invalid param: {err}"
+                    )))
+                    .await;
+                return Ok(());
+            }
+        };
+        let Some(accounts) = self
+            .subscription_streams
+            .subscribe_account(pubkey, commitment.unwrap_or_default().into())
+            .await
+        else {
+            pending.reject(accounts_not_streamed()).await;
+            return Ok(());
+        };
+
+        let sink = pending.accept().await?;
+        let encoding = encoding.unwrap_or(UiAccountEncoding::Binary);
+        spawn_subscription(sink, accounts, move |data| {
+            Some(account_notification(&data, encoding, data_slice))
+        });
+        Ok(())
+    }
+
+    async fn slot_subscribe(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        RPC_SLOT_SUBSCRIBE.inc();
+        let sink = pending.accept().await?;
+        spawn_subscription(sink, self.subscription_streams.subscribe_slots(), Some);
+        Ok(())
     }
 
     async fn block_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
-        _filter: RpcBlockSubscribeFilter,
-        _config: Option<RpcBlockSubscribeConfig>,
+        pending: PendingSubscriptionSink,
+        filter: RpcBlockSubscribeFilter,
+        config: Option<RpcBlockSubscribeConfig>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_BLOCK_SUBSCRIBE.inc();
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+        let mentions = check_is_at_least_confirmed(commitment_config).and_then(|_| match filter {
+            RpcBlockSubscribeFilter::All => Ok(None),
+            RpcBlockSubscribeFilter::MentionsAccountOrProgram(account) => {
+                Pubkey::from_str(&account)
+                    .map(Some)
+                    .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))
+            }
+        });
+        let mentions = match mentions {
+            Ok(mentions) => mentions,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            }
+        };
+        // same defaults as the solana block subscription
+        let block_config = RpcBlockConfig {
+            encoding: Some(config.encoding.unwrap_or(UiTransactionEncoding::Base64)),
+            transaction_details: config.transaction_details,
+            rewards: Some(config.show_rewards.unwrap_or_default()),
+            commitment: Some(commitment_config),
+            max_supported_transaction_version: config.max_supported_transaction_version,
+        };
+
+        let sink = pending.accept().await?;
+        spawn_subscription(
+            sink,
+            self.subscription_streams.subscribe_blocks(),
+            move |block| {
+                if block.commitment_config != commitment_config {
+                    return None;
+                }
+                match &mentions {
+                    Some(account) => block_notification(
+                        &block.with_transactions_mentioning(account)?,
+                        &block_config,
+                    ),
+                    None => block_notification(&block, &block_config),
+                }
+            },
+        );
+        Ok(())
+    }
+
+    async fn transaction_subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: RpcTransactionSubscribeFilter,
+        config: Option<RpcTransactionSubscribeConfig>,
+    ) -> SubscriptionResult {
+        RPC_TRANSACTION_SUBSCRIBE.inc();
+        let config = config.unwrap_or_default();
+        let transaction_details = config.transaction_details.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+        // processed blocks are not streamed
+        let filter = match transaction_details {
+            TransactionDetails::Accounts => Err(jsonrpsee::core::Error::Custom(
+                "Invalid param: transaction details `accounts` is not supported".to_string(),
+            )),
+            _ => check_is_at_least_confirmed(commitment_config)
+                .and_then(|_| TransactionFilter::new(filter, self.data_cache.txs.clone())),
+        };
+        let filter = match filter {
+            Ok(filter) => filter,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            }
+        };
+        let commitment_config = if commitment_config.is_finalized() {
+            CommitmentConfig::finalized()
+        } else {
+            CommitmentConfig::confirmed()
+        };
+        // same defaults as the block subscription
+        let options = TransactionEncodingOptions {
+            encoding: config.encoding.unwrap_or(UiTransactionEncoding::Base64),
+            transaction_details,
+            show_rewards: config.show_rewards.unwrap_or_default(),
+            max_supported_transaction_version: config.max_supported_transaction_version,
+        };
+
+        let sink = pending.accept().await?;
+        spawn_subscription(
+            sink,
+            self.subscription_streams.subscribe_blocks(),
+            move |block| {
+                if block.commitment_config != commitment_config {
+                    return vec![];
+                }
+                transaction_notifications(&block, &filter, options)
+            },
+        );
+        Ok(())
     }
 
     async fn logs_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
-        _filter: RpcTransactionLogsFilter,
-        _config: Option<RpcTransactionLogsConfig>,
+        pending: PendingSubscriptionSink,
+        filter: RpcTransactionLogsFilter,
+        config: Option<RpcTransactionLogsConfig>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_LOGS_SUBSCRIBE.inc();
+        if !self.keep_transaction_meta {
+            pending
+                .reject(jsonrpsee::core::Error::Custom(
+                    "Method not supported: transaction meta is not kept".to_string(),
+                ))
+                .await;
+            return Ok(());
+        }
+        let commitment_config = config
+            .and_then(|config| config.commitment)
+            .unwrap_or_default();
+        let filter = check_is_at_least_confirmed(commitment_config).and_then(|_| match filter {
+            RpcTransactionLogsFilter::All => Ok(LogsFilter::All),
+            RpcTransactionLogsFilter::AllWithVotes => Ok(LogsFilter::AllWithVotes),
+            RpcTransactionLogsFilter::Mentions(accounts) => match accounts.as_slice() {
+                [account] => Pubkey::from_str(account)
+                    .map(LogsFilter::Mentions)
+                    .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}"))),
+                _ => Err(jsonrpsee::core::Error::Custom(
+                    "Invalid Request: Only 1 address supported".to_string(),
+                )),
+            },
+        });
+        let filter = match filter {
+            Ok(filter) => filter,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            }
+        };
+
+        let sink = pending.accept().await?;
+        let logs = self
+            .subscription_streams
+            .subscribe_logs(filter, commitment_config)
+            .await;
+        spawn_subscription(sink, logs, Some);
+        Ok(())
     }
 
-    // WARN: enable_received_notification: bool is ignored
     async fn signature_subscribe(
         &self,
         pending: PendingSubscriptionSink,
@@ -435,21 +1543,79 @@ impl LiteRpcServer for LiteBridge {
         RPC_SIGNATURE_SUBSCRIBE.inc();
         let sink = pending.accept().await?;
 
-        let jsonrpsee_sink = JsonRpseeSubscriptionHandlerSink::new(sink);
+        let jsonrpsee_sink: SubscptionHanderSink =
+            Arc::new(JsonRpseeSubscriptionHandlerSink::new(sink));
         self.data_cache.tx_subs.signature_subscribe(
             signature,
             config.commitment.unwrap_or_default(),
-            Arc::new(jsonrpsee_sink),
+            jsonrpsee_sink,
+            config.enable_received_notification.unwrap_or_default(),
+            &self.data_cache.tx_lifecycles,
         );
 
         Ok(())
     }
 
-    async fn slot_updates_subscribe(
+    async fn transaction_lifecycle_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
+        pending: PendingSubscriptionSink,
+        signatures: Option<Vec<String>>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_TRANSACTION_LIFECYCLE_SUBSCRIBE.inc();
+        let signatures = signatures
+            .map(|signatures| {
+                signatures
+                    .into_iter()
+                    .map(|signature| {
+                        Signature::from_str(&signature)
+                            .map(|_| signature)
+                            .map_err(|err| {
+                                jsonrpsee::core::Error::Custom(format!("Invalid param: {err}"))
+                            })
+                    })
+                    .collect::<crate::rpc::Result<HashSet<_>>>()
+            })
+            .transpose();
+        let signatures = match signatures {
+            Ok(signatures) => signatures,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            }
+        };
+
+        let sink = pending.accept().await?;
+        spawn_subscription(
+            sink,
+            self.data_cache.tx_subs.subscribe_transaction_lifecycle(),
+            move |event| {
+                if signatures
+                    .as_ref()
+                    .is_some_and(|signatures| !signatures.contains(&event.signature))
+                {
+                    return None;
+                }
+                Some(RpcResponse {
+                    context: RpcResponseContext::new(event.slot),
+                    value: RpcTransactionLifecycleUpdate {
+                        signature: event.signature,
+                        status: event.status,
+                    },
+                })
+            },
+        );
+        Ok(())
+    }
+
+    async fn slot_updates_subscribe(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        RPC_SLOTS_UPDATES_SUBSCRIBE.inc();
+        let sink = pending.accept().await?;
+        spawn_subscription(
+            sink,
+            self.subscription_streams.subscribe_slot_updates(),
+            Some,
+        );
+        Ok(())
     }
 
     async fn vote_subscribe(&self, _pending: PendingSubscriptionSink) -> SubscriptionResult {
@@ -461,13 +1627,26 @@ impl LiteRpcServer for LiteBridge {
         slot: Option<u64>,
         config: Option<RpcLeaderScheduleConfig>,
     ) -> crate::rpc::Result<Option<HashMap<String, Vec<usize>>>> {
-        //TODO verify leader identity.
+        let config = config.unwrap_or_default();
+        let identity = config
+            .identity
+            .map(|identity| Pubkey::from_str(&identity))
+            .transpose()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+
+        // schedules calculated from geyser are verified with the rpc before being used
+        // when verify_leader_schedule is set
         let schedule = self
             .data_cache
             .leader_schedule
             .read()
             .await
-            .get_leader_schedule_for_slot(slot, config.and_then(|c| c.commitment), &self.data_cache)
+            .get_leader_schedule_for_slot(
+                slot,
+                config.commitment,
+                identity.as_ref(),
+                &self.data_cache,
+            )
             .await;
         Ok(schedule)
     }
@@ -489,10 +1668,178 @@ impl LiteRpcServer for LiteBridge {
             })
     }
 
+    async fn get_slot_leader(
+        &self,
+        config: Option<RpcContextConfig>,
+    ) -> crate::rpc::Result<String> {
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await;
+        check_min_context_slot(config.min_context_slot, slot)?;
+
+        // the leader fetchers return the leaders of a range of at least two slots
+        let slot_leaders = self
+            .leader_fetcher
+            .get_slot_leaders(slot, slot + 1)
+            .await
+            .map_err(|err| {
+                jsonrpsee::core::Error::Custom(format!("error during query processing:{err}"))
+            })?;
+        slot_leaders
+            .into_iter()
+            .find(|leader| leader.leader_slot == slot)
+            .map(|leader| leader.pubkey.to_string())
+            .ok_or_else(|| jsonrpsee::core::Error::Custom(format!("No leader for slot {slot}")))
+    }
+
+    async fn get_upcoming_leaders(
+        &self,
+        limit: Option<u64>,
+    ) -> crate::rpc::Result<Vec<RpcUpcomingLeader>> {
+        let limit = limit.unwrap_or(DEFAULT_FANOUT_SIZE);
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+        if limit > MAX_GET_SLOT_LEADERS as u64 {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Invalid Params: Invalid limit; max {MAX_GET_SLOT_LEADERS}"
+            )));
+        }
+        let BlockInformation { slot, .. } = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(CommitmentConfig::processed())
+            .await;
+
+        let next_slot = slot.saturating_add(1);
+        let last_slot = next_slot.saturating_add(limit);
+        let slot_leaders = self
+            .leader_fetcher
+            .get_slot_leaders(next_slot, last_slot)
+            .await
+            .map_err(|err| {
+                jsonrpsee::core::Error::Custom(format!("error during query processing:{err}"))
+            })?;
+
+        // same leaders and tpu address join as the tpu service
+        Ok(slot_leaders
+            .into_iter()
+            .filter(|leader| leader.leader_slot < last_slot)
+            .map(|leader| RpcUpcomingLeader {
+                slot: leader.leader_slot,
+                leader: leader.pubkey.to_string(),
+                tpu_quic: self
+                    .data_cache
+                    .cluster_info
+                    .get_tpu_quic_address(&leader.pubkey),
+            })
+            .collect())
+    }
+
     async fn get_vote_accounts(
         &self,
-        _config: Option<RpcGetVoteAccountsConfig>,
+        config: Option<RpcGetVoteAccountsConfig>,
     ) -> crate::rpc::Result<RpcVoteAccountStatus> {
-        todo!()
+        let config = config.unwrap_or_default();
+        if let Some(vote_accounts_sender) = &self.vote_accounts_sender {
+            let vote_accounts_config = GetVoteAccountsConfig::try_from(config.clone())
+                .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+            let (return_sender, return_receiver) = oneshot::channel();
+            if vote_accounts_sender
+                .send((vote_accounts_config, return_sender))
+                .await
+                .is_ok()
+            {
+                if let Ok(Some(vote_accounts)) = return_receiver.await {
+                    return Ok(vote_accounts);
+                }
+            }
+            log::debug!("No vote accounts computed from geyser for getVoteAccounts, using rpc");
+        }
+
+        self.rpc_client
+            .get_vote_accounts_with_config(config)
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(slot: Slot, confirmation_status: TransactionConfirmationStatus) -> TransactionStatus {
+        TransactionStatus {
+            slot,
+            confirmations: None,
+            status: Ok(()),
+            err: None,
+            confirmation_status: Some(confirmation_status),
+        }
+    }
+
+    #[test]
+    fn status_confirmations() {
+        let (confirmed_slot, finalized_slot) = (100, 60);
+
+        let processed = status_with_confirmations(
+            status(50, TransactionConfirmationStatus::Processed),
+            confirmed_slot,
+            finalized_slot,
+        );
+        assert_eq!(processed.confirmations, Some(0));
+        assert_eq!(
+            processed.confirmation_status,
+            Some(TransactionConfirmationStatus::Processed)
+        );
+
+        let confirmed = status_with_confirmations(
+            status(90, TransactionConfirmationStatus::Confirmed),
+            confirmed_slot,
+            finalized_slot,
+        );
+        assert_eq!(confirmed.confirmations, Some(11));
+        assert_eq!(
+            confirmed.confirmation_status,
+            Some(TransactionConfirmationStatus::Confirmed)
+        );
+
+        // up to the vote lockout history
+        let confirmed = status_with_confirmations(
+            status(61, TransactionConfirmationStatus::Confirmed),
+            confirmed_slot,
+            finalized_slot,
+        );
+        assert_eq!(confirmed.confirmations, Some(MAX_LOCKOUT_HISTORY));
+
+        // rooted with the finalized slot
+        for slot in [50, 60] {
+            let finalized = status_with_confirmations(
+                status(slot, TransactionConfirmationStatus::Confirmed),
+                confirmed_slot,
+                finalized_slot,
+            );
+            assert_eq!(finalized.confirmations, None);
+            assert_eq!(
+                finalized.confirmation_status,
+                Some(TransactionConfirmationStatus::Finalized)
+            );
+        }
+
+        let finalized = status_with_confirmations(
+            status(50, TransactionConfirmationStatus::Finalized),
+            confirmed_slot,
+            finalized_slot,
+        );
+        assert_eq!(finalized.confirmations, None);
+        assert_eq!(
+            finalized.confirmation_status,
+            Some(TransactionConfirmationStatus::Finalized)
+        );
     }
 }
@@ -44,6 +44,23 @@ pub struct Config {
     pub use_grpc: bool,
     #[serde(default)]
     pub calculate_leader_schedule_form_geyser: bool,
+    /// compare the leader schedule calculated from geyser with the rpc one before using it
+    #[serde(default)]
+    pub verify_leader_schedule: bool,
+    /// percent of slots with a different leader above which the rpc leader schedule is used instead
+    #[serde(default = "Config::default_max_leader_schedule_mismatch_percent")]
+    pub max_leader_schedule_mismatch_percent: f64,
+    /// file where the stakes and leader schedule calculated from geyser are saved at each epoch
+    /// change, used to restart without fetching all the stake accounts from the rpc
+    #[serde(default = "Config::default_stake_vote_snapshot_file")]
+    pub stake_vote_snapshot_file: String,
+    /// keep the full transaction meta of received blocks, disable to save memory,
+    /// `logsSubscribe` is not supported without it
+    #[serde(default = "Config::default_keep_transaction_meta")]
+    pub keep_transaction_meta: bool,
+    /// programs whose accounts are cached from the geyser account updates
+    #[serde(default)]
+    pub account_cache_programs: Vec<String>,
     #[serde(default = "Config::default_grpc_addr")]
     pub grpc_addr: String,
     #[serde(default)]
@@ -134,6 +151,31 @@ impl Config {
             .map(|_| true)
             .unwrap_or(config.use_grpc);
 
+        config.verify_leader_schedule = env::var("VERIFY_LEADER_SCHEDULE")
+            .map(|verify| verify.parse().unwrap())
+            .unwrap_or(config.verify_leader_schedule);
+
+        config.max_leader_schedule_mismatch_percent =
+            env::var("MAX_LEADER_SCHEDULE_MISMATCH_PERCENT")
+                .map(|percent| percent.parse().unwrap())
+                .unwrap_or(config.max_leader_schedule_mismatch_percent);
+
+        config.stake_vote_snapshot_file =
+            env::var("STAKE_VOTE_SNAPSHOT_FILE").unwrap_or(config.stake_vote_snapshot_file);
+
+        config.keep_transaction_meta = env::var("KEEP_TRANSACTION_META")
+            .map(|keep| keep.parse().unwrap())
+            .unwrap_or(config.keep_transaction_meta);
+
+        config.account_cache_programs = env::var("ACCOUNT_CACHE_PROGRAMS")
+            .map(|programs| {
+                programs
+                    .split(',')
+                    .map(|program| program.trim().to_string())
+                    .collect()
+            })
+            .unwrap_or(config.account_cache_programs);
+
         // source 1
         config.grpc_addr = env::var("GRPC_ADDR").unwrap_or(config.grpc_addr);
         config.grpc_x_token = env::var("GRPC_X_TOKEN")
@@ -214,6 +256,18 @@ impl Config {
         DEFAULT_RETRY_TIMEOUT
     }
 
+    pub const fn default_keep_transaction_meta() -> bool {
+        true
+    }
+
+    pub const fn default_max_leader_schedule_mismatch_percent() -> f64 {
+        1.0
+    }
+
+    pub fn default_stake_vote_snapshot_file() -> String {
+        "stake_vote_snapshot.bin".to_string()
+    }
+
     pub fn default_grpc_addr() -> String {
         DEFAULT_GRPC_ADDR.to_string()
     }
@@ -1,18 +1,21 @@
 use serde::{Deserialize, Serialize};
 use solana_lite_rpc_core::encoding::BinaryEncoding;
-use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::{
+    commitment_config::{CommitmentConfig, CommitmentLevel},
+    slot_history::Slot,
+};
+use solana_transaction_status::{TransactionDetails, UiTransactionEncoding};
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SendTransactionConfig {
-    //    #[serde(default)]
-    //    pub skip_preflight: bool,
-    //    #[serde(default)]
-    //    pub preflight_commitment: CommitmentLevel,
+    #[serde(default)]
+    pub skip_preflight: bool,
+    pub preflight_commitment: Option<CommitmentLevel>,
     #[serde(default)]
     pub encoding: BinaryEncoding,
     pub max_retries: Option<u16>,
-    //    pub min_context_slot: Option<Slot>,
+    pub min_context_slot: Option<Slot>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -21,3 +24,48 @@ pub struct IsBlockHashValidConfig {
     pub commitment: Option<CommitmentLevel>,
     //    pub minContextSlot: Option<u64>,
 }
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrioritizationFeePercentilesConfig {
+    // only transactions write locking one of these accounts are considered
+    pub account_keys: Option<Vec<String>>,
+    // number of recent slots considered, 150 at most
+    pub slots: Option<usize>,
+}
+
+/// filter of the `transactionSubscribe` method, a transaction is notified when it matches all of them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionSubscribeFilter {
+    // only vote transactions when true, only non vote transactions when false
+    pub vote: Option<bool>,
+    // only failed transactions when true, only successful transactions when false
+    pub failed: Option<bool>,
+    pub signatures: Option<Vec<String>>,
+    pub accounts: Option<RpcTransactionSubscribeAccounts>,
+    // lite-rpc extension, only the transactions sent through lite-rpc when true
+    pub sent_by_lite_rpc: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionSubscribeAccounts {
+    // at least one of these accounts is mentioned
+    pub include: Option<Vec<String>>,
+    // none of these accounts is mentioned
+    pub exclude: Option<Vec<String>>,
+    // all these accounts are mentioned
+    pub required: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionSubscribeConfig {
+    #[serde(flatten)]
+    pub commitment: Option<CommitmentConfig>,
+    pub encoding: Option<UiTransactionEncoding>,
+    pub transaction_details: Option<TransactionDetails>,
+    pub show_rewards: Option<bool>,
+    pub max_supported_transaction_version: Option<u8>,
+}
@@ -1,14 +1,18 @@
 use const_env::from_env;
 use solana_transaction_status::TransactionConfirmationStatus;
 
+pub mod account_subscriptions;
 pub mod bridge;
 pub mod cli;
 pub mod configs;
 pub mod errors;
 pub mod jsonrpsee_subscrption_handler_sink;
 pub mod postgres_logger;
+pub mod responses;
 pub mod rpc;
 pub mod service_spawner;
+pub mod subscriptions;
+pub mod transaction_subscriptions;
 
 #[from_env]
 pub const DEFAULT_RPC_ADDR: &str = "http://0.0.0.0:8899";
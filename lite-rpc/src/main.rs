@@ -1,15 +1,17 @@
 pub mod rpc_tester;
 
 use crate::rpc_tester::RpcTester;
-use anyhow::bail;
+use anyhow::{bail, Context};
 use dashmap::DashMap;
 use lite_rpc::bridge::LiteBridge;
 use lite_rpc::cli::Config;
 use lite_rpc::postgres_logger::PostgresLogger;
 use lite_rpc::service_spawner::ServiceSpawner;
+use lite_rpc::subscriptions::SubscriptionStreams;
 use lite_rpc::DEFAULT_MAX_NUMBER_OF_TXS_IN_QUEUE;
 use log::info;
 use solana_lite_rpc_cluster_endpoints::endpoint_stremers::EndpointStreaming;
+use solana_lite_rpc_cluster_endpoints::grpc_leaders_getter::GrpcLeaderGetter;
 use solana_lite_rpc_cluster_endpoints::grpc_subscription::create_grpc_subscription;
 use solana_lite_rpc_cluster_endpoints::grpc_subscription_autoreconnect::{
     GrpcConnectionTimeouts, GrpcSourceConfig,
@@ -18,10 +20,13 @@ use solana_lite_rpc_cluster_endpoints::json_rpc_leaders_getter::JsonRpcLeaderGet
 use solana_lite_rpc_cluster_endpoints::json_rpc_subscription::create_json_rpc_polling_subscription;
 use solana_lite_rpc_core::keypair_loader::load_identity_keypair;
 use solana_lite_rpc_core::stores::{
+    account_store::AccountStore,
     block_information_store::{BlockInformation, BlockInformationStore},
     cluster_info_store::ClusterInfo,
     data_cache::{DataCache, SlotCache},
+    prioritization_fees_store::PrioritizationFeesStore,
     subscription_store::SubscriptionStore,
+    tx_lifecycle_store::TxLifecycleStore,
     tx_store::TxStore,
 };
 use solana_lite_rpc_core::structures::leaderschedule::CalculatedSchedule;
@@ -29,6 +34,7 @@ use solana_lite_rpc_core::structures::{
     epoch::EpochCache, identity_stakes::IdentityStakes, notifications::NotificationSender,
     produced_block::ProducedBlock,
 };
+use solana_lite_rpc_core::traits::leaders_fetcher_interface::LeaderFetcherInterface;
 use solana_lite_rpc_core::types::BlockStream;
 use solana_lite_rpc_core::AnyhowJoinHandle;
 use solana_lite_rpc_history::block_stores::inmemory_block_store::InmemoryBlockStore;
@@ -36,16 +42,21 @@ use solana_lite_rpc_history::history::History;
 use solana_lite_rpc_history::postgres::postgres_config::PostgresSessionConfig;
 use solana_lite_rpc_history::postgres::postgres_session::PostgresSessionCache;
 use solana_lite_rpc_services::data_caching_service::DataCachingService;
+use solana_lite_rpc_services::performance_samples::PerformanceSamples;
 use solana_lite_rpc_services::quic_connection_utils::QuicConnectionParameters;
 use solana_lite_rpc_services::tpu_utils::tpu_connection_path::TpuConnectionPath;
 use solana_lite_rpc_services::tpu_utils::tpu_service::{TpuService, TpuServiceConfig};
 use solana_lite_rpc_services::transaction_replayer::TransactionReplayer;
 use solana_lite_rpc_services::tx_sender::TxSender;
+use solana_lite_rpc_stakevote::{bootstrat_literpc_leader_schedule, start_stakes_and_votes_loop};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use std::collections::BTreeSet;
 use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -87,6 +98,15 @@ pub async fn start_postgres(
 pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow::Result<()> {
     let grpc_sources = args.get_grpc_sources();
     log::info!("grpc_sources:{grpc_sources:?}");
+    let timeouts = GrpcConnectionTimeouts {
+        connect_timeout: Duration::from_secs(5),
+        request_timeout: Duration::from_secs(5),
+        subscribe_timeout: Duration::from_secs(5),
+    };
+    let grpc_sources = grpc_sources
+        .iter()
+        .map(|s| GrpcSourceConfig::new(s.addr.clone(), s.x_token.clone(), None, timeouts.clone()))
+        .collect::<Vec<_>>();
     let Config {
         lite_rpc_ws_addr,
         lite_rpc_http_addr,
@@ -98,9 +118,24 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         transaction_retry_after_secs,
         quic_proxy_addr,
         use_grpc,
+        calculate_leader_schedule_form_geyser,
+        verify_leader_schedule,
+        max_leader_schedule_mismatch_percent,
+        stake_vote_snapshot_file,
+        keep_transaction_meta,
+        account_cache_programs,
         ..
     } = args;
 
+    let account_cache_programs = account_cache_programs
+        .iter()
+        .map(|program| {
+            program
+                .parse::<Pubkey>()
+                .with_context(|| format!("Invalid account cache program {program}"))
+        })
+        .collect::<anyhow::Result<BTreeSet<Pubkey>>>()?;
+
     let validator_identity = Arc::new(
         load_identity_keypair(identity_keypair)
             .await?
@@ -111,23 +146,19 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
 
     let tpu_connection_path = configure_tpu_connection_path(quic_proxy_addr);
 
+    // the stake and vote accounts are only notified by geyser
+    let leader_schedule_from_geyser = use_grpc && calculate_leader_schedule_form_geyser;
+    // vote accounts are polled from the rpc until the stakes of the current epoch are computed from geyser
+    let poll_vote_accounts = Arc::new(AtomicBool::new(true));
+
     let (subscriptions, cluster_endpoint_tasks) = if use_grpc {
         info!("Creating geyser subscription...");
 
-        let timeouts = GrpcConnectionTimeouts {
-            connect_timeout: Duration::from_secs(5),
-            request_timeout: Duration::from_secs(5),
-            subscribe_timeout: Duration::from_secs(5),
-        };
-
         create_grpc_subscription(
             rpc_client.clone(),
-            grpc_sources
-                .iter()
-                .map(|s| {
-                    GrpcSourceConfig::new(s.addr.clone(), s.x_token.clone(), None, timeouts.clone())
-                })
-                .collect(),
+            grpc_sources.clone(),
+            keep_transaction_meta,
+            poll_vote_accounts.clone(),
         )?
 
         // create_grpc_subscription(
@@ -137,20 +168,32 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         // )?
     } else {
         info!("Creating RPC poll subscription...");
-        create_json_rpc_polling_subscription(rpc_client.clone())?
+        create_json_rpc_polling_subscription(rpc_client.clone(), keep_transaction_meta)?
     };
     let EndpointStreaming {
         blocks_notifier,
         cluster_info_notifier,
         slot_notifier,
         vote_account_notifier,
+        account_notifier,
+        account_filters_sender,
     } = subscriptions;
 
+    // accounts are cached from the account updates which are only streamed by geyser
+    let account_cache_programs = if account_notifier.is_some() {
+        account_cache_programs
+    } else {
+        if !account_cache_programs.is_empty() {
+            log::warn!("Account cache programs ignored, accounts are only streamed with grpc");
+        }
+        BTreeSet::new()
+    };
+
     let finalized_block =
         get_latest_block(blocks_notifier.resubscribe(), CommitmentConfig::finalized()).await;
     info!("Got finalized block: {:?}", finalized_block.slot);
 
-    let (epoch_data, _current_epoch_info) = EpochCache::bootstrap_epoch(&rpc_client).await?;
+    let (epoch_data, current_epoch_info) = EpochCache::bootstrap_epoch(&rpc_client).await?;
 
     let block_information_store =
         BlockInformationStore::new(BlockInformation::from_block(&finalized_block));
@@ -166,6 +209,41 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         },
         epoch_data,
         leader_schedule: Arc::new(RwLock::new(CalculatedSchedule::default())),
+        prioritization_fees: PrioritizationFeesStore::default(),
+        accounts: AccountStore::new(account_cache_programs.clone()),
+        tx_lifecycles: TxLifecycleStore::default(),
+    };
+
+    // stakes and votes are computed from geyser account notifications
+    let (vote_accounts_sender, stakes_and_votes_service) = if leader_schedule_from_geyser {
+        bootstrat_literpc_leader_schedule(
+            rpc_client.url(),
+            &data_cache,
+            current_epoch_info.epoch,
+            &stake_vote_snapshot_file,
+        )
+        .await;
+        let (vote_accounts_sender, vote_accounts_receiver) = mpsc::channel(1);
+        let stakes_and_votes_service = start_stakes_and_votes_loop(
+            data_cache.clone(),
+            slot_notifier.resubscribe(),
+            vote_accounts_receiver,
+            rpc_client.clone(),
+            poll_vote_accounts,
+            // the first source is the one of grpc_addr and grpc_x_token
+            grpc_sources[0].clone(),
+            verify_leader_schedule.then_some(max_leader_schedule_mismatch_percent),
+            stake_vote_snapshot_file,
+        )
+        .await?;
+        (Some(vote_accounts_sender), stakes_and_votes_service)
+    } else {
+        (
+            None,
+            tokio::spawn(async {
+                std::future::pending::<()>().await;
+            }),
+        )
     };
 
     let data_cache_service = DataCachingService {
@@ -179,9 +257,48 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         slot_notifier.resubscribe(),
         cluster_info_notifier,
         vote_account_notifier,
+        account_notifier.as_ref().map(|stream| stream.resubscribe()),
+    );
+
+    let history = History {
+        block_storage: Arc::new(InmemoryBlockStore::new(1024)),
+    };
+    let history_service = history.start_saving_blocks(blocks_notifier.resubscribe());
+
+    let performance_samples = PerformanceSamples::default();
+    let performance_samples_service =
+        performance_samples.start(blocks_notifier.resubscribe(), slot_notifier.resubscribe());
+    let (subscription_streams, subscription_streams_service) = SubscriptionStreams::start(
+        slot_notifier.resubscribe(),
+        blocks_notifier.resubscribe(),
+        account_notifier.zip(account_filters_sender),
+        account_cache_programs,
     );
     drop(blocks_notifier);
 
+    // seeded after the cached programs are added to the streamed accounts so updates made
+    // during the loading are not missed, the loaded accounts are then streamed by pubkey so
+    // their closing and owner changes are notified
+    let account_store = data_cache.accounts.clone();
+    let seeding_rpc_client = rpc_client.clone();
+    let seeding_streams = subscription_streams.clone();
+    tokio::spawn(async move {
+        let mut retry_after = Duration::from_secs(1);
+        while let Err(err) = account_store
+            .load_program_accounts(&seeding_rpc_client)
+            .await
+        {
+            log::error!(
+                "Loading the accounts of the cached programs failed, retrying in {retry_after:?}: {err:?}"
+            );
+            tokio::time::sleep(retry_after).await;
+            retry_after = (retry_after * 2).min(Duration::from_secs(60));
+        }
+        seeding_streams
+            .stream_cached_accounts(account_store.cached_accounts())
+            .await;
+    });
+
     let (notification_channel, postgres) = start_postgres(postgres).await?;
 
     let tpu_config = TpuServiceConfig {
@@ -204,17 +321,26 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         data_cache: data_cache.clone(),
     };
     //init grpc leader schedule and vote account is configured.
-    let leader_schedule = Arc::new(JsonRpcLeaderGetter::new(rpc_client.clone(), 1024, 128));
+    let rpc_leader_getter = JsonRpcLeaderGetter::new(rpc_client.clone(), 1024, 128);
+    let leader_schedule: Arc<dyn LeaderFetcherInterface> = if leader_schedule_from_geyser {
+        Arc::new(GrpcLeaderGetter::new(
+            data_cache.leader_schedule.clone(),
+            data_cache.epoch_data.clone(),
+            rpc_leader_getter,
+        ))
+    } else {
+        Arc::new(rpc_leader_getter)
+    };
     let tpu_service: TpuService = TpuService::new(
         tpu_config,
         validator_identity,
-        leader_schedule,
+        leader_schedule.clone(),
         data_cache.clone(),
     )
     .await?;
     let tx_sender = TxSender::new(data_cache.clone(), tpu_service.clone());
     let tx_replayer =
-        TransactionReplayer::new(tpu_service.clone(), data_cache.txs.clone(), retry_after);
+        TransactionReplayer::new(tpu_service.clone(), data_cache.clone(), retry_after);
     let (transaction_service, tx_service_jh) = spawner.spawn_tx_service(
         tx_sender,
         tx_replayer,
@@ -229,16 +355,17 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
 
     let support_service = tokio::spawn(async move { spawner.spawn_support_services().await });
 
-    let history = History {
-        block_storage: Arc::new(InmemoryBlockStore::new(1024)),
-    };
-
     let bridge_service = tokio::spawn(
         LiteBridge::new(
             rpc_client.clone(),
             data_cache.clone(),
             transaction_service,
             history,
+            performance_samples,
+            vote_accounts_sender,
+            subscription_streams,
+            leader_schedule,
+            keep_transaction_meta,
         )
         .start(lite_rpc_http_addr, lite_rpc_ws_addr),
     );
@@ -255,6 +382,18 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         res = postgres => {
             anyhow::bail!("Postgres service {res:?}");
         }
+        res = history_service => {
+            anyhow::bail!("History service {res:?}");
+        }
+        res = performance_samples_service => {
+            anyhow::bail!("Performance samples service {res:?}");
+        }
+        res = stakes_and_votes_service => {
+            anyhow::bail!("Stakes and votes service {res:?}");
+        }
+        res = subscription_streams_service => {
+            anyhow::bail!("Subscription streams service {res:?}");
+        }
         res = futures::future::select_all(data_caching_service) => {
             anyhow::bail!("Data caching service failed {res:?}")
         }
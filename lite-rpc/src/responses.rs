@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use solana_lite_rpc_core::structures::transaction_lifecycle::{
+    QuicSendOutcome, TransactionExpiryReason, TransactionForward, TransactionLifecycle,
+    TransactionLifecycleStatus,
+};
+use solana_rpc_client_api::response::RpcContactInfo;
+use solana_sdk::{slot_history::Slot, transaction::TransactionError};
+use solana_transaction_status::{EncodedTransactionWithStatusMeta, TransactionConfirmationStatus};
+use std::{collections::HashMap, net::SocketAddr};
+
+/// contact info used by lite-rpc for a node, response of the `getClusterNode` extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcClusterNode {
+    pub contact_info: Option<RpcContactInfo>,
+    /// unix timestamp in seconds of the last cluster info update
+    pub last_update: Option<u64>,
+}
+
+/// prioritization fees (micro-lamports per compute unit) paid over a window of recent slots,
+/// percentiles are weighted by the compute units consumed by each transaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPrioritizationFeePercentiles {
+    pub first_slot: Slot,
+    pub last_slot: Slot,
+    pub nb_transactions: usize,
+    pub p25: u64,
+    pub p50: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub p99: u64,
+}
+
+/// leader of an upcoming slot, response of the `getUpcomingLeaders` extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcUpcomingLeader {
+    pub slot: Slot,
+    pub leader: String,
+    /// none when the leader is not in the cluster info
+    pub tpu_quic: Option<SocketAddr>,
+}
+
+/// transaction included in a block, notification of the `transactionSubscribe` method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionUpdate {
+    pub signature: String,
+    pub slot: Slot,
+    pub confirmation_status: TransactionConfirmationStatus,
+    pub err: Option<TransactionError>,
+    /// none unless the full transaction details are requested
+    pub transaction: Option<EncodedTransactionWithStatusMeta>,
+}
+
+/// state change of a transaction sent through lite-rpc,
+/// notification of the `transactionLifecycleSubscribe` extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionLifecycleUpdate {
+    pub signature: String,
+    pub status: TransactionLifecycleStatus,
+}
+
+/// forward of a transaction to the tpu of the leaders, part of `RpcTransactionLifecycle`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionForward {
+    pub slot: Slot,
+    /// unix timestamp in milliseconds
+    pub forwarded_at: i64,
+    pub leaders: Vec<String>,
+    /// outcome of the quic send by leader, missing for the leaders not reached yet
+    pub quic_outcomes: HashMap<String, QuicSendOutcome>,
+}
+
+impl From<TransactionForward> for RpcTransactionForward {
+    fn from(forward: TransactionForward) -> Self {
+        Self {
+            slot: forward.slot,
+            forwarded_at: forward.forwarded_at.timestamp_millis(),
+            leaders: forward.leaders.iter().map(|x| x.to_string()).collect(),
+            quic_outcomes: forward
+                .quic_outcomes
+                .into_iter()
+                .map(|(leader, outcome)| (leader.to_string(), outcome))
+                .collect(),
+        }
+    }
+}
+
+/// lifecycle of a transaction sent through lite-rpc, response of the `getTransactionLifecycles` extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionLifecycle {
+    /// unix timestamp in milliseconds
+    pub received_at: i64,
+    pub last_valid_block_height: u64,
+    pub forwards: Vec<RpcTransactionForward>,
+    pub replay_count: usize,
+    pub landed_slot: Option<Slot>,
+    pub confirmation_status: Option<TransactionConfirmationStatus>,
+    pub expiry_reason: Option<TransactionExpiryReason>,
+}
+
+impl From<TransactionLifecycle> for RpcTransactionLifecycle {
+    fn from(lifecycle: TransactionLifecycle) -> Self {
+        Self {
+            received_at: lifecycle.received_at.timestamp_millis(),
+            last_valid_block_height: lifecycle.last_valid_blockheight,
+            forwards: lifecycle.forwards.into_iter().map(Into::into).collect(),
+            replay_count: lifecycle.replay_count,
+            landed_slot: lifecycle.landed_slot,
+            confirmation_status: lifecycle.confirmation_status,
+            expiry_reason: lifecycle.expiry_reason,
+        }
+    }
+}
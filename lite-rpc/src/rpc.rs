@@ -1,17 +1,26 @@
-use crate::configs::{IsBlockHashValidConfig, SendTransactionConfig};
+use crate::configs::{
+    IsBlockHashValidConfig, PrioritizationFeePercentilesConfig, RpcTransactionSubscribeConfig,
+    RpcTransactionSubscribeFilter, SendTransactionConfig,
+};
+use crate::responses::{
+    RpcClusterNode, RpcPrioritizationFeePercentiles, RpcTransactionLifecycle, RpcUpcomingLeader,
+};
 use jsonrpsee::core::SubscriptionResult;
 use jsonrpsee::proc_macros::rpc;
+use solana_account_decoder::UiAccount;
 use solana_rpc_client_api::config::{
-    RpcBlockConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter, RpcBlocksConfigWrapper,
-    RpcContextConfig, RpcEncodingConfigWrapper, RpcGetVoteAccountsConfig, RpcLeaderScheduleConfig,
-    RpcProgramAccountsConfig, RpcRequestAirdropConfig, RpcSignatureStatusConfig,
-    RpcSignatureSubscribeConfig, RpcSignaturesForAddressConfig, RpcTransactionLogsConfig,
-    RpcTransactionLogsFilter,
+    RpcAccountInfoConfig, RpcBlockConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
+    RpcBlocksConfigWrapper, RpcContextConfig, RpcEncodingConfigWrapper, RpcGetVoteAccountsConfig,
+    RpcLeaderScheduleConfig, RpcProgramAccountsConfig, RpcRequestAirdropConfig,
+    RpcSignatureStatusConfig, RpcSignatureSubscribeConfig, RpcSignaturesForAddressConfig,
+    RpcTransactionConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter,
 };
 use solana_rpc_client_api::response::{
-    Response as RpcResponse, RpcBlockhash, RpcConfirmedTransactionStatusWithSignature,
-    RpcContactInfo, RpcPerfSample, RpcPrioritizationFee, RpcVersionInfo, RpcVoteAccountStatus,
+    OptionalContext, Response as RpcResponse, RpcBlockhash,
+    RpcConfirmedTransactionStatusWithSignature, RpcContactInfo, RpcKeyedAccount, RpcPerfSample,
+    RpcPrioritizationFee, RpcVersionInfo, RpcVoteAccountStatus,
 };
+use solana_sdk::clock::UnixTimestamp;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::epoch_info::EpochInfo;
 use solana_sdk::pubkey::Pubkey;
@@ -42,6 +51,14 @@ pub trait LiteRpc {
         commitment: Option<CommitmentConfig>,
     ) -> Result<Vec<Slot>>;
 
+    #[method(name = "getBlocksWithLimit")]
+    async fn get_blocks_with_limit(
+        &self,
+        start_slot: Slot,
+        limit: usize,
+        config: Option<RpcContextConfig>,
+    ) -> Result<Vec<Slot>>;
+
     #[method(name = "getSignaturesForAddress")]
     async fn get_signatures_for_address(
         &self,
@@ -49,14 +66,14 @@ pub trait LiteRpc {
         config: Option<RpcSignaturesForAddressConfig>,
     ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>>;
 
-    // issue:  solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta does not implement Clone
-    //
-    //#[method(name = "getTransaction")]
-    //async fn get_transaction(
-    //    &self,
-    //    signature_str: String,
-    //    config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
-    //) -> Result<Option<EncodedConfirmedTransactionWithStatusMeta>>;
+    // solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta does not implement Clone,
+    // the encoded transaction is returned as json value instead
+    #[method(name = "getTransaction")]
+    async fn get_transaction(
+        &self,
+        signature_str: String,
+        config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+    ) -> Result<Option<serde_json::Value>>;
 
     // ***********************
     // Cluster Domain
@@ -65,6 +82,10 @@ pub trait LiteRpc {
     #[method(name = "getClusterNodes")]
     async fn get_cluster_nodes(&self) -> Result<Vec<RpcContactInfo>>;
 
+    // lite-rpc extension, contact info used for a node and when it was last updated
+    #[method(name = "getClusterNode")]
+    async fn get_cluster_node(&self, pubkey: String) -> Result<RpcClusterNode>;
+
     // ***********************
     // Validator Domain
     // ***********************
@@ -76,7 +97,7 @@ pub trait LiteRpc {
     async fn get_block_height(&self, config: Option<RpcContextConfig>) -> Result<u64>;
 
     #[method(name = "getBlockTime")]
-    async fn get_block_time(&self, block: u64) -> Result<u64>;
+    async fn get_block_time(&self, block: u64) -> Result<Option<UnixTimestamp>>;
 
     #[method(name = "getFirstAvailableBlock")]
     async fn get_first_available_block(&self) -> Result<u64>;
@@ -129,6 +150,45 @@ pub trait LiteRpc {
         pubkey_strs: Option<Vec<String>>,
     ) -> Result<Vec<RpcPrioritizationFee>>;
 
+    // lite-rpc extension, percentiles of the prioritization fees paid in recent slots
+    #[method(name = "getPrioritizationFeePercentiles")]
+    async fn get_prioritization_fee_percentiles(
+        &self,
+        config: Option<PrioritizationFeePercentilesConfig>,
+    ) -> Result<RpcPrioritizationFeePercentiles>;
+
+    // ***********************
+    // Account Domain
+    // ***********************
+
+    #[method(name = "getAccountInfo")]
+    async fn get_account_info(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<RpcResponse<Option<UiAccount>>>;
+
+    #[method(name = "getMultipleAccounts")]
+    async fn get_multiple_accounts(
+        &self,
+        pubkey_strs: Vec<String>,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<RpcResponse<Vec<Option<UiAccount>>>>;
+
+    #[method(name = "getBalance")]
+    async fn get_balance(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcContextConfig>,
+    ) -> Result<RpcResponse<u64>>;
+
+    #[method(name = "getProgramAccounts")]
+    async fn get_program_accounts(
+        &self,
+        program_id_str: String,
+        config: Option<RpcProgramAccountsConfig>,
+    ) -> Result<OptionalContext<Vec<RpcKeyedAccount>>>;
+
     // ***********************
     // Send Transaction Domain
     // ***********************
@@ -140,6 +200,14 @@ pub trait LiteRpc {
         send_transaction_config: Option<SendTransactionConfig>,
     ) -> Result<String>;
 
+    // lite-rpc extension, reception, forwards to the leaders, landing and expiry of the
+    // transactions sent through lite-rpc, null for the unknown ones
+    #[method(name = "getTransactionLifecycles")]
+    async fn get_transaction_lifecycles(
+        &self,
+        signature_strs: Vec<String>,
+    ) -> Result<Vec<Option<RpcTransactionLifecycle>>>;
+
     // ***********************
     // Deprecated
     // ***********************
@@ -159,17 +227,24 @@ pub trait LiteRpc {
     // Direct Subscription Domain
     // ***********************
 
-    #[subscription(name = "programSubscribe" => "programNotification", unsubscribe="programUnsubscribe", item=RpcResponse<serde_json::Value>)]
+    #[subscription(name = "programSubscribe" => "programNotification", unsubscribe="programUnsubscribe", item=RpcResponse<RpcKeyedAccount>)]
     async fn program_subscribe(
         &self,
         pubkey_str: String,
         config: Option<RpcProgramAccountsConfig>,
     ) -> SubscriptionResult;
 
-    #[subscription(name = "slotSubscribe" => "slotNotification", unsubscribe="slotUnsubscribe", item=Slot)]
+    #[subscription(name = "accountSubscribe" => "accountNotification", unsubscribe="accountUnsubscribe", item=RpcResponse<UiAccount>)]
+    async fn account_subscribe(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> SubscriptionResult;
+
+    #[subscription(name = "slotSubscribe" => "slotNotification", unsubscribe="slotUnsubscribe", item=SlotInfo)]
     async fn slot_subscribe(&self) -> SubscriptionResult;
 
-    #[subscription(name = "blockSubscribe" => "blockNotification", unsubscribe="blockUnsubscribe", item=RpcResponse<UiConfirmedBlock>)]
+    #[subscription(name = "blockSubscribe" => "blockNotification", unsubscribe="blockUnsubscribe", item=RpcResponse<RpcBlockUpdate>)]
     async fn block_subscribe(
         &self,
         filter: RpcBlockSubscribeFilter,
@@ -177,12 +252,14 @@ pub trait LiteRpc {
     ) -> SubscriptionResult;
 
     // [transactionSubscribe](https://github.com/solana-foundation/solana-improvement-documents/pull/69)
-    //
-    //#[subscription(name = "transactionSubscribe" => "transactionNotification", unsubscribe="transactionUnsubscribe", item=RpcResponse<RpcConfirmedTransactionStatusWithSignature>)]
-    //async fn transaction_subscribe(
-    //    &self,
-    //    commitment_config: CommitmentConfig,
-    //) -> SubscriptionResult;
+    // the transactions are notified from the confirmed and finalized blocks, processed blocks are
+    // not streamed so a `processed` commitment is rejected instead of notifying at `confirmed`
+    #[subscription(name = "transactionSubscribe" => "transactionNotification", unsubscribe="transactionUnsubscribe", item=RpcResponse<RpcTransactionUpdate>)]
+    async fn transaction_subscribe(
+        &self,
+        filter: RpcTransactionSubscribeFilter,
+        config: Option<RpcTransactionSubscribeConfig>,
+    ) -> SubscriptionResult;
 
     // ***********************
     // Indirect Subscription Domain
@@ -195,7 +272,6 @@ pub trait LiteRpc {
         config: Option<RpcTransactionLogsConfig>,
     ) -> SubscriptionResult;
 
-    // WARN: enable_received_notification: bool is ignored
     #[subscription(name = "signatureSubscribe" => "signatureNotification", unsubscribe="signatureUnsubscribe", item=RpcResponse<serde_json::Value>)]
     async fn signature_subscribe(
         &self,
@@ -203,7 +279,15 @@ pub trait LiteRpc {
         config: RpcSignatureSubscribeConfig,
     ) -> SubscriptionResult;
 
-    #[subscription(name = "slotUpdatesSubscribe" => "slotUpdatesNotification", unsubscribe="slotUpdatesUnsubscribe", item=SlotUpdate)]
+    // lite-rpc extension, forwarded, replayed, landed and expired states of the transactions sent
+    // through lite-rpc, all of them unless signatures are given
+    #[subscription(name = "transactionLifecycleSubscribe" => "transactionLifecycleNotification", unsubscribe="transactionLifecycleUnsubscribe", item=RpcResponse<RpcTransactionLifecycleUpdate>)]
+    async fn transaction_lifecycle_subscribe(
+        &self,
+        signatures: Option<Vec<String>>,
+    ) -> SubscriptionResult;
+
+    #[subscription(name = "slotsUpdatesSubscribe" => "slotsUpdatesNotification", unsubscribe="slotsUpdatesUnsubscribe", item=SlotUpdate)]
     async fn slot_updates_subscribe(&self) -> SubscriptionResult;
 
     #[subscription(name = "voteSubscribe" => "voteNotification", unsubscribe="voteUnsubscribe", item=RpcVote)]
@@ -229,6 +313,18 @@ pub trait LiteRpc {
         limit: u64,
     ) -> crate::rpc::Result<Vec<Pubkey>>;
 
+    #[method(name = "getSlotLeader")]
+    async fn get_slot_leader(&self, config: Option<RpcContextConfig>)
+        -> crate::rpc::Result<String>;
+
+    // lite-rpc extension, leaders of the next slots with the tpu quic address transactions are sent to,
+    // at most `MAX_GET_SLOT_LEADERS` like getSlotLeaders
+    #[method(name = "getUpcomingLeaders")]
+    async fn get_upcoming_leaders(
+        &self,
+        limit: Option<u64>,
+    ) -> crate::rpc::Result<Vec<RpcUpcomingLeader>>;
+
     #[method(name = "getVoteAccounts")]
     async fn get_vote_accounts(
         &self,
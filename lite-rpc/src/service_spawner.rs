@@ -1,7 +1,7 @@
 use solana_lite_rpc_core::{
     stores::data_cache::DataCache,
     structures::notifications::NotificationSender,
-    types::{BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream},
+    types::{AccountStream, BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream},
     AnyhowJoinHandle,
 };
 use solana_lite_rpc_services::{
@@ -44,6 +44,7 @@ impl ServiceSpawner {
         slot_notification: SlotStream,
         cluster_info_notification: ClusterInfoStream,
         va_notification: VoteAccountStream,
+        account_notification: Option<AccountStream>,
     ) -> Vec<AnyhowJoinHandle> {
         let data_service = DataCachingService {
             data_cache: self.data_cache.clone(),
@@ -55,6 +56,7 @@ impl ServiceSpawner {
             slot_notification,
             cluster_info_notification,
             va_notification,
+            account_notification,
         )
     }
 
@@ -78,6 +80,7 @@ impl ServiceSpawner {
         service_builder.start(
             notifier,
             self.data_cache.block_information_store.clone(),
+            self.data_cache.tx_lifecycles.clone(),
             max_retries,
             slot_notifications,
         )
@@ -0,0 +1,744 @@
+use crate::account_subscriptions::AccountSubscriptions;
+use jsonrpsee::{core::server::TrySendError, SubscriptionMessage, SubscriptionSink};
+use prometheus::{opts, register_int_counter, IntCounter};
+use serde::Serialize;
+use solana_lite_rpc_core::{
+    commitment_utils::Commitment,
+    structures::account_data::{AccountData, AccountNotificationMessage},
+    structures::{
+        produced_block::{ProducedBlock, TransactionInfo},
+        slot_notification::SlotNotification,
+    },
+    types::{AccountFiltersSender, AccountStream, BlockStream, SlotStream},
+    AnyhowJoinHandle,
+};
+use solana_rpc_client_api::{
+    config::RpcBlockConfig,
+    filter::RpcFilterType,
+    response::{
+        Response as RpcResponse, RpcBlockUpdate, RpcBlockUpdateError, RpcLogsResponse,
+        RpcResponseContext, SlotInfo, SlotUpdate,
+    },
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot, timing::timestamp,
+};
+use solana_transaction_status::EncodeError;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    RwLock,
+};
+
+lazy_static::lazy_static! {
+    static ref SUBSCRIPTION_NOTIFICATIONS_DROPPED: IntCounter =
+    register_int_counter!(opts!("literpc_subscription_notifications_dropped", "Notifications dropped for websocket clients not reading them fast enough")).unwrap();
+}
+
+// notifications buffered for each subscription before the slowest ones start to skip some
+const SLOT_NOTIFICATIONS_CAPACITY: usize = 1000;
+const BLOCK_NOTIFICATIONS_CAPACITY: usize = 64;
+const LOGS_NOTIFICATIONS_CAPACITY: usize = 1000;
+// unsubscribed accounts are removed from the streamed accounts at least this often
+const REMOVE_CLOSED_ACCOUNT_SUBSCRIPTIONS_INTERVAL: Duration = Duration::from_secs(10);
+
+type LogsNotification = RpcResponse<RpcLogsResponse>;
+type LogsSubscription = (CommitmentConfig, broadcast::Sender<LogsNotification>);
+
+/// Transactions notified to a logs subscription
+#[derive(Debug, Clone, Copy)]
+pub enum LogsFilter {
+    /// all transactions except the votes
+    All,
+    AllWithVotes,
+    /// transactions mentioning the account or program
+    Mentions(Pubkey),
+}
+
+/// Logs subscriptions indexed by mentioned account so a block is matched by looking up
+/// the accounts of its transactions instead of going through all the subscriptions.
+#[derive(Default)]
+struct LogsSubscriptions {
+    all: Vec<LogsSubscription>,
+    all_with_votes: Vec<LogsSubscription>,
+    mentions: HashMap<Pubkey, Vec<LogsSubscription>>,
+}
+
+impl LogsSubscriptions {
+    fn is_empty(&self) -> bool {
+        self.all.is_empty() && self.all_with_votes.is_empty() && self.mentions.is_empty()
+    }
+
+    fn subscribe(
+        &mut self,
+        filter: LogsFilter,
+        commitment_config: CommitmentConfig,
+    ) -> broadcast::Receiver<LogsNotification> {
+        let (sender, receiver) = broadcast::channel(LOGS_NOTIFICATIONS_CAPACITY);
+        let subscription = (commitment_config, sender);
+        match filter {
+            LogsFilter::All => self.all.push(subscription),
+            LogsFilter::AllWithVotes => self.all_with_votes.push(subscription),
+            LogsFilter::Mentions(account) => {
+                self.mentions.entry(account).or_default().push(subscription)
+            }
+        }
+        receiver
+    }
+
+    // subscriptions whose filter matches the transaction, at any commitment
+    fn matching(&self, tx: &TransactionInfo) -> Vec<&Vec<LogsSubscription>> {
+        let mut matching: Vec<&Vec<LogsSubscription>> = vec![];
+        if !self.all_with_votes.is_empty() {
+            matching.push(&self.all_with_votes);
+        }
+        if !self.all.is_empty() && !tx.is_vote() {
+            matching.push(&self.all);
+        }
+        if !self.mentions.is_empty() {
+            matching.extend(
+                tx.account_keys()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|account| self.mentions.get(account)),
+            );
+        }
+        matching
+    }
+
+    // remove the subscriptions whose client has unsubscribed
+    fn remove_closed(&mut self) {
+        let is_open = |(_, sender): &LogsSubscription| sender.receiver_count() > 0;
+        self.all.retain(is_open);
+        self.all_with_votes.retain(is_open);
+        self.mentions.retain(|_, subscriptions| {
+            subscriptions.retain(is_open);
+            !subscriptions.is_empty()
+        });
+    }
+}
+
+/// Slot updates of the processed slots notified by the cluster endpoint.
+///
+/// The bank of a slot is created when the slot is first seen, its parent is completed since
+/// the child bank is created from the frozen parent bank.
+#[derive(Default)]
+struct ProcessedSlots {
+    last_processed_slot: Slot,
+    last_completed_slot: Slot,
+}
+
+impl ProcessedSlots {
+    /// Parent and slot updates of a processed slot, none when the slot was already notified
+    fn slot_updates(
+        &mut self,
+        processed_slot: Slot,
+        parent_slot: Option<Slot>,
+        timestamp: u64,
+    ) -> Option<(Slot, Vec<SlotUpdate>)> {
+        // estimated slot updates notify the same processed slot again
+        if processed_slot <= self.last_processed_slot {
+            return None;
+        }
+        // without geyser the last processed slot is used as parent
+        let parent = parent_slot.unwrap_or(if self.last_processed_slot > 0 {
+            self.last_processed_slot
+        } else {
+            processed_slot.saturating_sub(1)
+        });
+
+        let mut slot_updates = Vec::with_capacity(2);
+        // the parent is only completed when its bank creation was notified
+        if self.last_processed_slot > 0 && parent > self.last_completed_slot {
+            self.last_completed_slot = parent;
+            slot_updates.push(SlotUpdate::Completed {
+                slot: parent,
+                timestamp,
+            });
+        }
+        slot_updates.push(SlotUpdate::CreatedBank {
+            slot: processed_slot,
+            parent,
+            timestamp,
+        });
+        self.last_processed_slot = processed_slot;
+        Some((parent, slot_updates))
+    }
+}
+
+/// Notifications of the websocket subscriptions.
+///
+/// Subscribers receive their own receiver created from the senders, no idle receiver is kept
+/// so the blocks are not retained when nobody subscribes.
+#[derive(Clone)]
+pub struct SubscriptionStreams {
+    slot_sender: broadcast::Sender<SlotInfo>,
+    slot_updates_sender: broadcast::Sender<SlotUpdate>,
+    block_sender: broadcast::Sender<Arc<ProducedBlock>>,
+    logs_subscriptions: Arc<RwLock<LogsSubscriptions>>,
+    // only set when the cluster endpoint streams accounts
+    account_subscriptions: Option<Arc<RwLock<AccountSubscriptions>>>,
+}
+
+impl SubscriptionStreams {
+    /// Build the subscription notifications from the slot and block streams of the cluster endpoint.
+    ///
+    /// Processed slots are notified as `createdBank` slot updates and their parent as `completed`,
+    /// confirmed and finalized blocks as `optimisticConfirmation` and `root`.
+    /// The slot statuses notified by geyser don't include the first shred received.
+    /// Accounts are notified when the cluster endpoint streams them, the programs of the account
+    /// cache are always streamed.
+    pub fn start(
+        mut slot_stream: SlotStream,
+        mut block_stream: BlockStream,
+        accounts: Option<(AccountStream, AccountFiltersSender)>,
+        cached_programs: BTreeSet<Pubkey>,
+    ) -> (Self, AnyhowJoinHandle) {
+        let (mut account_stream, account_filters_sender) = accounts.unzip();
+        let streams = Self {
+            slot_sender: broadcast::channel(SLOT_NOTIFICATIONS_CAPACITY).0,
+            slot_updates_sender: broadcast::channel(SLOT_NOTIFICATIONS_CAPACITY).0,
+            block_sender: broadcast::channel(BLOCK_NOTIFICATIONS_CAPACITY).0,
+            logs_subscriptions: Arc::new(RwLock::new(LogsSubscriptions::default())),
+            account_subscriptions: account_filters_sender.map(|filters_sender| {
+                Arc::new(RwLock::new(AccountSubscriptions::new(
+                    filters_sender,
+                    cached_programs,
+                )))
+            }),
+        };
+
+        let jh: AnyhowJoinHandle = tokio::spawn({
+            let streams = streams.clone();
+            async move {
+                let mut processed_slots = ProcessedSlots::default();
+                let mut root: Slot = 0;
+                let mut remove_closed_interval =
+                    tokio::time::interval(REMOVE_CLOSED_ACCOUNT_SUBSCRIPTIONS_INTERVAL);
+                loop {
+                    tokio::select! {
+                        slot_notification = slot_stream.recv() => {
+                            match slot_notification {
+                                Ok(SlotNotification { processed_slot, parent_slot, .. }) => {
+                                    if let Some((parent, slot_updates)) = processed_slots.slot_updates(processed_slot, parent_slot, timestamp()) {
+                                        streams.notify_processed_slot(processed_slot, parent, root, slot_updates);
+                                    }
+                                }
+                                Err(RecvError::Lagged(nb_skipped)) => {
+                                    log::warn!("Subscription streams lagging, {nb_skipped} slots skipped");
+                                }
+                                Err(RecvError::Closed) => anyhow::bail!("Slot stream closed"),
+                            }
+                        }
+                        block = block_stream.recv() => {
+                            match block {
+                                Ok(block) => {
+                                    if block.commitment_config == CommitmentConfig::finalized() {
+                                        root = root.max(block.slot);
+                                    }
+                                    streams.notify_logs(&block).await;
+                                    streams.notify_block(block);
+                                }
+                                Err(RecvError::Lagged(nb_skipped)) => {
+                                    log::warn!("Subscription streams lagging, {nb_skipped} blocks skipped");
+                                }
+                                Err(RecvError::Closed) => anyhow::bail!("Block stream closed"),
+                            }
+                        }
+                        account = recv_account(&mut account_stream) => {
+                            match account {
+                                Ok(message) => streams.notify_account(&message).await,
+                                Err(RecvError::Lagged(nb_skipped)) => {
+                                    log::warn!("Subscription streams lagging, {nb_skipped} account updates skipped");
+                                }
+                                Err(RecvError::Closed) => anyhow::bail!("Account stream closed"),
+                            }
+                        }
+                        _ = remove_closed_interval.tick() => {
+                            if let Some(account_subscriptions) = &streams.account_subscriptions {
+                                account_subscriptions.write().await.remove_closed();
+                            }
+                        }
+                    }
+                }
+            }
+        });
+        (streams, jh)
+    }
+
+    pub fn subscribe_slots(&self) -> broadcast::Receiver<SlotInfo> {
+        self.slot_sender.subscribe()
+    }
+
+    pub fn subscribe_slot_updates(&self) -> broadcast::Receiver<SlotUpdate> {
+        self.slot_updates_sender.subscribe()
+    }
+
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<Arc<ProducedBlock>> {
+        self.block_sender.subscribe()
+    }
+
+    /// Updates of the account at the commitment, none if the accounts are not streamed
+    pub async fn subscribe_account(
+        &self,
+        pubkey: Pubkey,
+        commitment: Commitment,
+    ) -> Option<broadcast::Receiver<AccountData>> {
+        let account_subscriptions = self.account_subscriptions.as_ref()?;
+        Some(
+            account_subscriptions
+                .write()
+                .await
+                .subscribe_account(pubkey, commitment),
+        )
+    }
+
+    /// Updates of the accounts owned by the program at the commitment, the accounts not matching
+    /// the filters may be notified too, none if the accounts are not streamed
+    pub async fn subscribe_program(
+        &self,
+        program_id: Pubkey,
+        filters: Vec<RpcFilterType>,
+        commitment: Commitment,
+    ) -> Option<broadcast::Receiver<AccountData>> {
+        let account_subscriptions = self.account_subscriptions.as_ref()?;
+        Some(
+            account_subscriptions
+                .write()
+                .await
+                .subscribe_program(program_id, filters, commitment),
+        )
+    }
+
+    async fn notify_account(&self, message: &AccountNotificationMessage) {
+        let Some(account_subscriptions) = &self.account_subscriptions else {
+            return;
+        };
+        if account_subscriptions.read().await.notify(message) {
+            account_subscriptions
+                .write()
+                .await
+                .update_streamed(&message.data);
+        }
+    }
+
+    /// Stream the accounts of the account cache by pubkey so their closing is notified,
+    /// the accounts of new owners are not streamed by their program
+    pub async fn stream_cached_accounts(&self, pubkeys: Vec<Pubkey>) {
+        if let Some(account_subscriptions) = &self.account_subscriptions {
+            account_subscriptions
+                .write()
+                .await
+                .stream_cached_accounts(pubkeys);
+        }
+    }
+
+    /// Logs of the transactions matching the filter in the blocks at the commitment.
+    pub async fn subscribe_logs(
+        &self,
+        filter: LogsFilter,
+        commitment_config: CommitmentConfig,
+    ) -> broadcast::Receiver<LogsNotification> {
+        let mut logs_subscriptions = self.logs_subscriptions.write().await;
+        logs_subscriptions.remove_closed();
+        logs_subscriptions.subscribe(filter, commitment_config)
+    }
+
+    // log messages are only known when the transaction meta is kept
+    async fn notify_logs(&self, block: &ProducedBlock) {
+        let logs_subscriptions = self.logs_subscriptions.read().await;
+        if logs_subscriptions.is_empty() {
+            return;
+        }
+
+        let mut has_closed = false;
+        for tx in &block.transactions {
+            let matching = logs_subscriptions.matching(tx);
+            if matching.is_empty() {
+                continue;
+            }
+
+            let notification = RpcResponse {
+                context: RpcResponseContext::new(block.slot),
+                value: RpcLogsResponse {
+                    signature: tx.signature.clone(),
+                    err: tx.err.clone(),
+                    logs: tx
+                        .meta
+                        .as_ref()
+                        .and_then(|meta| meta.log_messages.clone())
+                        .unwrap_or_default(),
+                },
+            };
+            for (commitment_config, sender) in matching.into_iter().flatten() {
+                if *commitment_config == block.commitment_config
+                    && sender.send(notification.clone()).is_err()
+                {
+                    has_closed = true;
+                }
+            }
+        }
+        drop(logs_subscriptions);
+
+        if has_closed {
+            self.logs_subscriptions.write().await.remove_closed();
+        }
+    }
+
+    // sending fails when there is no subscriber, the notification is just dropped
+    fn notify_processed_slot(
+        &self,
+        slot: Slot,
+        parent: Slot,
+        root: Slot,
+        slot_updates: Vec<SlotUpdate>,
+    ) {
+        let _ = self.slot_sender.send(SlotInfo { slot, parent, root });
+        for slot_update in slot_updates {
+            let _ = self.slot_updates_sender.send(slot_update);
+        }
+    }
+
+    fn notify_block(&self, block: ProducedBlock) {
+        let slot = block.slot;
+        let timestamp = timestamp();
+        let slot_update = if block.commitment_config == CommitmentConfig::finalized() {
+            Some(SlotUpdate::Root { slot, timestamp })
+        } else if block.commitment_config == CommitmentConfig::confirmed() {
+            Some(SlotUpdate::OptimisticConfirmation { slot, timestamp })
+        } else {
+            None
+        };
+        if let Some(slot_update) = slot_update {
+            let _ = self.slot_updates_sender.send(slot_update);
+        }
+        if self.block_sender.receiver_count() > 0 {
+            let _ = self.block_sender.send(Arc::new(block));
+        }
+    }
+}
+
+async fn recv_account(
+    account_stream: &mut Option<AccountStream>,
+) -> Result<AccountNotificationMessage, RecvError> {
+    match account_stream {
+        Some(account_stream) => account_stream.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Block notification encoded with the options of the subscription, like solana the error is
+/// notified instead of the block when it contains an unsupported transaction version.
+pub fn block_notification(
+    block: &ProducedBlock,
+    config: &RpcBlockConfig,
+) -> Option<RpcResponse<RpcBlockUpdate>> {
+    let (ui_block, err) = match block.to_ui_block(config) {
+        Ok(ui_block) => (Some(ui_block), None),
+        Err(err) => match err.downcast_ref::<EncodeError>() {
+            Some(EncodeError::UnsupportedTransactionVersion(version)) => (
+                None,
+                Some(RpcBlockUpdateError::UnsupportedTransactionVersion(*version)),
+            ),
+            None => {
+                log::error!("Error encoding block {} notification: {err}", block.slot);
+                return None;
+            }
+        },
+    };
+    Some(RpcResponse {
+        context: RpcResponseContext::new(block.slot),
+        value: RpcBlockUpdate {
+            slot: block.slot,
+            block: ui_block,
+            err,
+        },
+    })
+}
+
+/// Forward the notifications built by `to_notification` to the subscription until the client
+/// unsubscribes or the stream is closed, a streamed value can be notified zero or several times.
+///
+/// Notifications are dropped for a client not reading them fast enough,
+/// a slow client never blocks the shared streams or the other subscriptions.
+pub fn spawn_subscription<T, N, I, F>(
+    mut sink: SubscriptionSink,
+    mut stream: broadcast::Receiver<T>,
+    mut to_notification: F,
+) where
+    T: Clone + Send + 'static,
+    N: Serialize,
+    I: IntoIterator<Item = N>,
+    F: FnMut(T) -> I + Send + 'static,
+{
+    tokio::spawn(async move {
+        loop {
+            let value = tokio::select! {
+                _ = sink.closed() => break,
+                value = stream.recv() => value,
+            };
+            let value = match value {
+                Ok(value) => value,
+                Err(RecvError::Lagged(nb_skipped)) => {
+                    SUBSCRIPTION_NOTIFICATIONS_DROPPED.inc_by(nb_skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            for notification in to_notification(value) {
+                let message = match SubscriptionMessage::from_json(&notification) {
+                    Ok(message) => message,
+                    Err(err) => {
+                        log::error!(
+                            "Error serializing {} notification: {err}",
+                            sink.method_name()
+                        );
+                        continue;
+                    }
+                };
+                match sink.try_send(message) {
+                    Ok(()) => (),
+                    Err(TrySendError::Full(_)) => SUBSCRIPTION_NOTIFICATIONS_DROPPED.inc(),
+                    Err(TrySendError::Closed(_)) => return,
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_lite_rpc_core::encoding::BinaryEncoding;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::Instruction,
+        message::{Message, VersionedMessage},
+        signature::Keypair,
+        signer::Signer,
+        system_instruction,
+        transaction::Transaction,
+        vote,
+    };
+
+    const TIMESTAMP: u64 = 1_700_000_000_000;
+
+    fn created_bank(slot: Slot, parent: Slot) -> SlotUpdate {
+        SlotUpdate::CreatedBank {
+            slot,
+            parent,
+            timestamp: TIMESTAMP,
+        }
+    }
+
+    fn completed(slot: Slot) -> SlotUpdate {
+        SlotUpdate::Completed {
+            slot,
+            timestamp: TIMESTAMP,
+        }
+    }
+
+    #[test]
+    fn processed_slots_create_banks_and_complete_their_parent() {
+        let mut processed_slots = ProcessedSlots::default();
+
+        // the parent of the first slot was not seen created
+        assert_eq!(
+            processed_slots.slot_updates(100, Some(99), TIMESTAMP),
+            Some((99, vec![created_bank(100, 99)]))
+        );
+        assert_eq!(
+            processed_slots.slot_updates(101, Some(100), TIMESTAMP),
+            Some((100, vec![completed(100), created_bank(101, 100)]))
+        );
+        // a skipped slot
+        assert_eq!(
+            processed_slots.slot_updates(103, Some(101), TIMESTAMP),
+            Some((101, vec![completed(101), created_bank(103, 101)]))
+        );
+    }
+
+    #[test]
+    fn processed_slots_are_notified_once() {
+        let mut processed_slots = ProcessedSlots::default();
+
+        assert!(processed_slots
+            .slot_updates(100, Some(99), TIMESTAMP)
+            .is_some());
+        assert_eq!(processed_slots.slot_updates(100, Some(99), TIMESTAMP), None);
+        assert_eq!(processed_slots.slot_updates(98, Some(97), TIMESTAMP), None);
+    }
+
+    #[test]
+    fn completed_parent_is_notified_once() {
+        let mut processed_slots = ProcessedSlots::default();
+
+        processed_slots.slot_updates(100, Some(99), TIMESTAMP);
+        processed_slots.slot_updates(101, Some(100), TIMESTAMP);
+        // fork built on the same parent
+        assert_eq!(
+            processed_slots.slot_updates(102, Some(100), TIMESTAMP),
+            Some((100, vec![created_bank(102, 100)]))
+        );
+    }
+
+    #[test]
+    fn last_processed_slot_is_the_parent_without_geyser() {
+        let mut processed_slots = ProcessedSlots::default();
+
+        assert_eq!(
+            processed_slots.slot_updates(100, None, TIMESTAMP),
+            Some((99, vec![created_bank(100, 99)]))
+        );
+        assert_eq!(
+            processed_slots.slot_updates(102, None, TIMESTAMP),
+            Some((100, vec![completed(100), created_bank(102, 100)]))
+        );
+    }
+
+    fn transaction_info(instruction: Instruction, payer: &Keypair) -> TransactionInfo {
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let tx = Transaction::new(&[payer], message, Hash::default());
+        TransactionInfo {
+            signature: tx.signatures[0].to_string(),
+            err: None,
+            cu_requested: None,
+            prioritization_fees: None,
+            cu_consumed: None,
+            recent_blockhash: tx.message.recent_blockhash.to_string(),
+            message: BinaryEncoding::Base64
+                .encode(VersionedMessage::Legacy(tx.message.clone()).serialize()),
+            signatures: vec![],
+            meta: None,
+        }
+    }
+
+    fn vote_transaction() -> TransactionInfo {
+        transaction_info(
+            Instruction::new_with_bytes(vote::program::id(), &[], vec![]),
+            &Keypair::new(),
+        )
+    }
+
+    fn transfer(payer: &Keypair, recipient: &Pubkey) -> TransactionInfo {
+        transaction_info(
+            system_instruction::transfer(&payer.pubkey(), recipient, 1),
+            payer,
+        )
+    }
+
+    // number of subscriptions notified of the transaction
+    fn nb_matching(logs_subscriptions: &LogsSubscriptions, tx: &TransactionInfo) -> usize {
+        logs_subscriptions
+            .matching(tx)
+            .into_iter()
+            .flatten()
+            .count()
+    }
+
+    #[test]
+    fn mentions_are_indexed_by_account() {
+        let account = Pubkey::new_unique();
+        let mut logs_subscriptions = LogsSubscriptions::default();
+        assert!(logs_subscriptions.is_empty());
+
+        let _first = logs_subscriptions
+            .subscribe(LogsFilter::Mentions(account), CommitmentConfig::confirmed());
+        let _second = logs_subscriptions
+            .subscribe(LogsFilter::Mentions(account), CommitmentConfig::finalized());
+
+        assert!(!logs_subscriptions.is_empty());
+        assert_eq!(logs_subscriptions.mentions.len(), 1);
+        assert_eq!(logs_subscriptions.mentions[&account].len(), 2);
+    }
+
+    #[test]
+    fn all_matches_non_vote_transactions() {
+        let mut logs_subscriptions = LogsSubscriptions::default();
+        let _all = logs_subscriptions.subscribe(LogsFilter::All, CommitmentConfig::confirmed());
+
+        assert_eq!(
+            nb_matching(
+                &logs_subscriptions,
+                &transfer(&Keypair::new(), &Pubkey::new_unique())
+            ),
+            1
+        );
+        assert_eq!(nb_matching(&logs_subscriptions, &vote_transaction()), 0);
+    }
+
+    #[test]
+    fn all_with_votes_matches_all_transactions() {
+        let mut logs_subscriptions = LogsSubscriptions::default();
+        let _all_with_votes =
+            logs_subscriptions.subscribe(LogsFilter::AllWithVotes, CommitmentConfig::confirmed());
+
+        assert_eq!(
+            nb_matching(
+                &logs_subscriptions,
+                &transfer(&Keypair::new(), &Pubkey::new_unique())
+            ),
+            1
+        );
+        assert_eq!(nb_matching(&logs_subscriptions, &vote_transaction()), 1);
+    }
+
+    #[test]
+    fn mentions_match_transactions_mentioning_the_account() {
+        let payer = Keypair::new();
+        let recipient = Pubkey::new_unique();
+        let mut logs_subscriptions = LogsSubscriptions::default();
+        let _payer = logs_subscriptions.subscribe(
+            LogsFilter::Mentions(payer.pubkey()),
+            CommitmentConfig::confirmed(),
+        );
+        let _recipient = logs_subscriptions.subscribe(
+            LogsFilter::Mentions(recipient),
+            CommitmentConfig::confirmed(),
+        );
+        let _program = logs_subscriptions.subscribe(
+            LogsFilter::Mentions(solana_sdk::system_program::id()),
+            CommitmentConfig::confirmed(),
+        );
+        let _other = logs_subscriptions.subscribe(
+            LogsFilter::Mentions(Pubkey::new_unique()),
+            CommitmentConfig::confirmed(),
+        );
+
+        assert_eq!(
+            nb_matching(&logs_subscriptions, &transfer(&payer, &recipient)),
+            3
+        );
+        assert_eq!(
+            nb_matching(
+                &logs_subscriptions,
+                &transfer(&Keypair::new(), &Pubkey::new_unique())
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn closed_subscriptions_are_removed() {
+        let account = Pubkey::new_unique();
+        let mut logs_subscriptions = LogsSubscriptions::default();
+        let all = logs_subscriptions.subscribe(LogsFilter::All, CommitmentConfig::confirmed());
+        let _all_with_votes =
+            logs_subscriptions.subscribe(LogsFilter::AllWithVotes, CommitmentConfig::confirmed());
+        let mentions = logs_subscriptions
+            .subscribe(LogsFilter::Mentions(account), CommitmentConfig::confirmed());
+
+        drop(all);
+        drop(mentions);
+        logs_subscriptions.remove_closed();
+
+        assert!(logs_subscriptions.all.is_empty());
+        assert_eq!(logs_subscriptions.all_with_votes.len(), 1);
+        assert!(!logs_subscriptions.mentions.contains_key(&account));
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{
+    configs::{RpcTransactionSubscribeAccounts, RpcTransactionSubscribeFilter},
+    responses::RpcTransactionUpdate,
+};
+use solana_lite_rpc_core::{
+    commitment_utils::Commitment,
+    stores::tx_store::TxStore,
+    structures::produced_block::{ProducedBlock, TransactionInfo},
+};
+use solana_rpc_client_api::response::{Response as RpcResponse, RpcResponseContext};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{
+    EncodeError, EncodedTransactionWithStatusMeta, TransactionConfirmationStatus,
+    TransactionDetails, UiTransactionEncoding,
+};
+use std::{collections::HashSet, str::FromStr};
+
+/// Parsed filter of a `transactionSubscribe` subscription
+pub struct TransactionFilter {
+    vote: Option<bool>,
+    failed: Option<bool>,
+    signatures: Option<HashSet<String>>,
+    account_include: HashSet<Pubkey>,
+    account_exclude: HashSet<Pubkey>,
+    account_required: HashSet<Pubkey>,
+    sent_by_lite_rpc: Option<bool>,
+    // transactions sent by lite-rpc
+    txs: TxStore,
+}
+
+impl TransactionFilter {
+    pub fn new(filter: RpcTransactionSubscribeFilter, txs: TxStore) -> crate::rpc::Result<Self> {
+        let invalid_param =
+            |err: String| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}"));
+        let parse_accounts = |accounts: Option<Vec<String>>| {
+            accounts
+                .unwrap_or_default()
+                .iter()
+                .map(|account| Pubkey::from_str(account))
+                .collect::<Result<HashSet<_>, _>>()
+                .map_err(|err| invalid_param(err.to_string()))
+        };
+
+        let signatures = filter
+            .signatures
+            .map(|signatures| {
+                signatures
+                    .into_iter()
+                    .map(|signature| {
+                        Signature::from_str(&signature)
+                            .map(|_| signature)
+                            .map_err(|err| invalid_param(err.to_string()))
+                    })
+                    .collect::<crate::rpc::Result<HashSet<_>>>()
+            })
+            .transpose()?;
+        let RpcTransactionSubscribeAccounts {
+            include,
+            exclude,
+            required,
+        } = filter.accounts.unwrap_or_default();
+
+        Ok(Self {
+            vote: filter.vote,
+            failed: filter.failed,
+            signatures,
+            account_include: parse_accounts(include)?,
+            account_exclude: parse_accounts(exclude)?,
+            account_required: parse_accounts(required)?,
+            sent_by_lite_rpc: filter.sent_by_lite_rpc,
+            txs,
+        })
+    }
+
+    pub fn matches(&self, tx: &TransactionInfo) -> bool {
+        if self.failed.is_some_and(|failed| failed != tx.err.is_some()) {
+            return false;
+        }
+        if self
+            .signatures
+            .as_ref()
+            .is_some_and(|signatures| !signatures.contains(&tx.signature))
+        {
+            return false;
+        }
+        if let Some(sent_by_lite_rpc) = self.sent_by_lite_rpc {
+            let is_sent_by_lite_rpc = self
+                .txs
+                .get(&tx.signature)
+                .is_some_and(|props| props.sent_by_lite_rpc);
+            if is_sent_by_lite_rpc != sent_by_lite_rpc {
+                return false;
+            }
+        }
+        if self.vote.is_some_and(|vote| vote != tx.is_vote()) {
+            return false;
+        }
+
+        if self.account_include.is_empty()
+            && self.account_exclude.is_empty()
+            && self.account_required.is_empty()
+        {
+            return true;
+        }
+        let Ok(account_keys) = tx.account_keys() else {
+            return false;
+        };
+        (self.account_include.is_empty()
+            || account_keys
+                .iter()
+                .any(|key| self.account_include.contains(key)))
+            && !account_keys
+                .iter()
+                .any(|key| self.account_exclude.contains(key))
+            && self
+                .account_required
+                .iter()
+                .all(|key| account_keys.contains(key))
+    }
+}
+
+/// Encoding of the transactions notified by a `transactionSubscribe` subscription
+#[derive(Clone, Copy)]
+pub struct TransactionEncodingOptions {
+    pub encoding: UiTransactionEncoding,
+    pub transaction_details: TransactionDetails,
+    pub show_rewards: bool,
+    pub max_supported_transaction_version: Option<u8>,
+}
+
+/// `transactionNotification` of each transaction of the block matching the filter.
+///
+/// Transactions with a version above the max supported transaction version are not notified.
+pub fn transaction_notifications(
+    block: &ProducedBlock,
+    filter: &TransactionFilter,
+    options: TransactionEncodingOptions,
+) -> Vec<RpcResponse<RpcTransactionUpdate>> {
+    let confirmation_status = match Commitment::from(block.commitment_config) {
+        Commitment::Finalized => TransactionConfirmationStatus::Finalized,
+        Commitment::Confirmed => TransactionConfirmationStatus::Confirmed,
+        Commitment::Processed => TransactionConfirmationStatus::Processed,
+    };
+    block
+        .transactions
+        .iter()
+        .filter(|tx| filter.matches(tx))
+        .filter_map(|tx| {
+            let transaction = match options.transaction_details {
+                TransactionDetails::Full => Some(encode_transaction(tx, options)?),
+                _ => None,
+            };
+            Some(RpcResponse {
+                context: RpcResponseContext::new(block.slot),
+                value: RpcTransactionUpdate {
+                    signature: tx.signature.clone(),
+                    slot: block.slot,
+                    confirmation_status: confirmation_status.clone(),
+                    err: tx.err.clone(),
+                    transaction,
+                },
+            })
+        })
+        .collect()
+}
+
+fn encode_transaction(
+    tx: &TransactionInfo,
+    options: TransactionEncodingOptions,
+) -> Option<EncodedTransactionWithStatusMeta> {
+    let encoded = tx.to_transaction_with_status_meta().and_then(|tx| {
+        Ok(tx.encode(
+            options.encoding,
+            options.max_supported_transaction_version,
+            options.show_rewards,
+        )?)
+    });
+    match encoded {
+        Ok(encoded) => Some(encoded),
+        Err(err) => {
+            if !matches!(
+                err.downcast_ref::<EncodeError>(),
+                Some(EncodeError::UnsupportedTransactionVersion(_))
+            ) {
+                log::error!(
+                    "Error encoding transaction {} notification: {err}",
+                    tx.signature
+                );
+            }
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_lite_rpc_core::{encoding::BinaryEncoding, stores::data_cache::DataCache};
+    use solana_sdk::{
+        hash::Hash,
+        instruction::Instruction,
+        message::{Message, VersionedMessage},
+        signature::Keypair,
+        signer::Signer,
+        system_instruction,
+        transaction::Transaction,
+        vote,
+    };
+
+    fn transaction_info(instruction: Instruction, payer: &Keypair) -> TransactionInfo {
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let tx = Transaction::new(&[payer], message, Hash::default());
+        TransactionInfo {
+            signature: tx.signatures[0].to_string(),
+            err: None,
+            cu_requested: None,
+            prioritization_fees: None,
+            cu_consumed: None,
+            recent_blockhash: tx.message.recent_blockhash.to_string(),
+            message: BinaryEncoding::Base64
+                .encode(VersionedMessage::Legacy(tx.message.clone()).serialize()),
+            signatures: vec![],
+            meta: None,
+        }
+    }
+
+    fn transfer(payer: &Keypair, recipient: &Pubkey) -> TransactionInfo {
+        transaction_info(
+            system_instruction::transfer(&payer.pubkey(), recipient, 1),
+            payer,
+        )
+    }
+
+    fn filter(filter: RpcTransactionSubscribeFilter) -> TransactionFilter {
+        TransactionFilter::new(filter, DataCache::new_for_tests().txs).unwrap()
+    }
+
+    fn accounts_filter(
+        include: &[Pubkey],
+        exclude: &[Pubkey],
+        required: &[Pubkey],
+    ) -> TransactionFilter {
+        let to_strings =
+            |keys: &[Pubkey]| Some(keys.iter().map(Pubkey::to_string).collect::<Vec<_>>());
+        filter(RpcTransactionSubscribeFilter {
+            accounts: Some(RpcTransactionSubscribeAccounts {
+                include: to_strings(include),
+                exclude: to_strings(exclude),
+                required: to_strings(required),
+            }),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn signature_filter() {
+        let tx = transfer(&Keypair::new(), &Pubkey::new_unique());
+        let other_tx = transfer(&Keypair::new(), &Pubkey::new_unique());
+        let filter = filter(RpcTransactionSubscribeFilter {
+            signatures: Some(vec![tx.signature.clone()]),
+            ..Default::default()
+        });
+
+        assert!(filter.matches(&tx));
+        assert!(!filter.matches(&other_tx));
+    }
+
+    #[test]
+    fn invalid_signature_is_rejected() {
+        let filter = RpcTransactionSubscribeFilter {
+            signatures: Some(vec!["invalid".to_string()]),
+            ..Default::default()
+        };
+
+        assert!(TransactionFilter::new(filter, DataCache::new_for_tests().txs).is_err());
+    }
+
+    #[test]
+    fn account_filters() {
+        let payer = Keypair::new();
+        let recipient = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let tx = transfer(&payer, &recipient);
+
+        assert!(accounts_filter(&[], &[], &[]).matches(&tx));
+
+        assert!(accounts_filter(&[recipient, other], &[], &[]).matches(&tx));
+        assert!(!accounts_filter(&[other], &[], &[]).matches(&tx));
+
+        assert!(accounts_filter(&[], &[other], &[]).matches(&tx));
+        assert!(!accounts_filter(&[], &[recipient], &[]).matches(&tx));
+        assert!(!accounts_filter(&[recipient], &[payer.pubkey()], &[]).matches(&tx));
+
+        assert!(accounts_filter(&[], &[], &[recipient, payer.pubkey()]).matches(&tx));
+        assert!(!accounts_filter(&[], &[], &[recipient, other]).matches(&tx));
+    }
+
+    #[test]
+    fn vote_filter() {
+        let payer = Keypair::new();
+        let vote_tx = transaction_info(
+            Instruction::new_with_bytes(vote::program::id(), &[], vec![]),
+            &payer,
+        );
+        let tx = transfer(&payer, &Pubkey::new_unique());
+        let votes = filter(RpcTransactionSubscribeFilter {
+            vote: Some(true),
+            ..Default::default()
+        });
+        let non_votes = filter(RpcTransactionSubscribeFilter {
+            vote: Some(false),
+            ..Default::default()
+        });
+        let all = filter(RpcTransactionSubscribeFilter::default());
+
+        assert!(votes.matches(&vote_tx));
+        assert!(!votes.matches(&tx));
+        assert!(!non_votes.matches(&vote_tx));
+        assert!(non_votes.matches(&tx));
+        assert!(all.matches(&vote_tx));
+        assert!(all.matches(&tx));
+    }
+}
@@ -6,11 +6,15 @@ use prometheus::{opts, register_int_counter, register_int_gauge, IntCounter};
 use solana_lite_rpc_core::stores::{
     block_information_store::BlockInformation, data_cache::DataCache,
 };
-use solana_lite_rpc_core::types::{BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream};
+use solana_lite_rpc_core::structures::transaction_lifecycle::TransactionLifecycleStatus;
+use solana_lite_rpc_core::types::{
+    AccountStream, BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream,
+};
 use solana_lite_rpc_core::AnyhowJoinHandle;
 use solana_sdk::clock::MAX_RECENT_BLOCKHASHES;
 use solana_sdk::commitment_config::CommitmentLevel;
 use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+use tokio::sync::broadcast::error::RecvError;
 
 lazy_static::lazy_static! {
     static ref NB_CLUSTER_NODES: GenericGauge<prometheus::core::AtomicI64> =
@@ -44,6 +48,7 @@ impl DataCachingService {
         slot_notification: SlotStream,
         cluster_info_notification: ClusterInfoStream,
         va_notification: VoteAccountStream,
+        account_notification: Option<AccountStream>,
     ) -> Vec<AnyhowJoinHandle> {
         // clone the ledger to move into the processor task
         let data_cache = self.data_cache.clone();
@@ -58,6 +63,11 @@ impl DataCachingService {
                     .add_block(BlockInformation::from_block(&block))
                     .await;
 
+                // processed blocks can still be on a minority fork
+                if !block.commitment_config.is_processed() {
+                    data_cache.prioritization_fees.add_block(&block).await;
+                }
+
                 let confirmation_status = match block.commitment_config.commitment {
                     CommitmentLevel::Finalized => TransactionConfirmationStatus::Finalized,
                     CommitmentLevel::Confirmed => TransactionConfirmationStatus::Confirmed,
@@ -85,7 +95,27 @@ impl DataCachingService {
                         },
                         last_valid_blockheight,
                     ) {
-                        // transaction updated
+                        // transaction sent by lite-rpc updated
+                        data_cache.tx_subs.notify_transaction_lifecycle(
+                            &tx.signature,
+                            match confirmation_status {
+                                TransactionConfirmationStatus::Finalized => {
+                                    TransactionLifecycleStatus::Finalized
+                                }
+                                TransactionConfirmationStatus::Confirmed => {
+                                    TransactionLifecycleStatus::Confirmed
+                                }
+                                TransactionConfirmationStatus::Processed => {
+                                    TransactionLifecycleStatus::Processed
+                                }
+                            },
+                            block.slot,
+                        );
+                        data_cache.tx_lifecycles.landed(
+                            &tx.signature,
+                            block.slot,
+                            confirmation_status.clone(),
+                        );
                         match confirmation_status {
                             TransactionConfirmationStatus::Finalized => {
                                 TXS_FINALIZED.inc();
@@ -151,6 +181,29 @@ impl DataCachingService {
             }
         });
 
+        let data_cache: DataCache = self.data_cache.clone();
+        let account_cache_jh = tokio::spawn(async move {
+            let Some(mut account_notification) = account_notification else {
+                // accounts are only cached when they are streamed
+                std::future::pending::<()>().await;
+                unreachable!()
+            };
+            loop {
+                match account_notification.recv().await {
+                    Ok(message) => {
+                        data_cache
+                            .accounts
+                            .update_account(message.data, message.commitment);
+                    }
+                    Err(RecvError::Lagged(nb_skipped)) => {
+                        // the skipped accounts are stale until their next update
+                        log::error!("Account cache lagging, {nb_skipped} account updates skipped");
+                    }
+                    Err(RecvError::Closed) => bail!("Account stream closed"),
+                }
+            }
+        });
+
         let data_cache: DataCache = self.data_cache;
         let clean_ttl = self.clean_duration;
         let cleaning_service = tokio::spawn(async move {
@@ -165,6 +218,7 @@ impl DataCachingService {
             block_cache_jh,
             cluster_info_jh,
             identity_stakes_jh,
+            account_cache_jh,
             cleaning_service,
         ]
     }
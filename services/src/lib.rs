@@ -1,5 +1,6 @@
 pub mod data_caching_service;
 pub mod metrics_capture;
+pub mod performance_samples;
 pub mod prometheus_sync;
 pub mod quic_connection;
 pub mod quic_connection_utils;
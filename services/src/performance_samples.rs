@@ -0,0 +1,137 @@
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+use anyhow::bail;
+use log::warn;
+use prometheus::{core::GenericGauge, opts, register_int_gauge};
+use solana_lite_rpc_core::{
+    types::{BlockStream, SlotStream},
+    AnyhowJoinHandle,
+};
+use solana_rpc_client_api::response::RpcPerfSample;
+use solana_sdk::slot_history::Slot;
+use tokio::sync::{broadcast::error::RecvError, RwLock};
+
+lazy_static::lazy_static! {
+    static ref SAMPLE_NB_SLOTS: GenericGauge<prometheus::core::AtomicI64> =
+    register_int_gauge!(opts!("literpc_sample_nb_slots", "Number of slots in the last performance sample")).unwrap();
+
+    static ref SAMPLE_NB_TRANSACTIONS: GenericGauge<prometheus::core::AtomicI64> =
+    register_int_gauge!(opts!("literpc_sample_nb_transactions", "Number of transactions in the last performance sample")).unwrap();
+
+    static ref SAMPLE_NB_NON_VOTE_TRANSACTIONS: GenericGauge<prometheus::core::AtomicI64> =
+    register_int_gauge!(opts!("literpc_sample_nb_non_vote_transactions", "Number of non vote transactions in the last performance sample")).unwrap();
+}
+
+// same values as the validator, 12 hours of samples
+pub const PERFORMANCE_SAMPLES_LIMIT: usize = 720;
+pub const SAMPLE_PERIOD_SECS: u16 = 60;
+
+#[derive(Default)]
+struct SampleCounters {
+    num_transactions: u64,
+    num_non_vote_transactions: u64,
+}
+
+/// Ring buffer of the performance samples of the cluster as seen by lite-rpc,
+/// one sample every `SAMPLE_PERIOD_SECS` seconds
+#[derive(Clone, Default)]
+pub struct PerformanceSamples {
+    // newest sample first
+    samples: Arc<RwLock<VecDeque<RpcPerfSample>>>,
+}
+
+impl PerformanceSamples {
+    /// the `limit` most recent samples, newest first
+    pub async fn get_recent_samples(&self, limit: usize) -> Vec<RpcPerfSample> {
+        self.samples
+            .read()
+            .await
+            .iter()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    async fn add_sample(&self, sample: RpcPerfSample) {
+        SAMPLE_NB_SLOTS.set(sample.num_slots as i64);
+        SAMPLE_NB_TRANSACTIONS.set(sample.num_transactions as i64);
+        SAMPLE_NB_NON_VOTE_TRANSACTIONS
+            .set(sample.num_non_vote_transactions.unwrap_or_default() as i64);
+
+        let mut samples = self.samples.write().await;
+        samples.push_front(sample);
+        samples.truncate(PERFORMANCE_SAMPLES_LIMIT);
+    }
+
+    /// count the transactions of the confirmed blocks and the processed slots in each sample period
+    pub fn start(&self, block_stream: BlockStream, slot_stream: SlotStream) -> AnyhowJoinHandle {
+        let performance_samples = self.clone();
+        tokio::spawn(async move {
+            let mut block_stream = block_stream;
+            let mut slot_stream = slot_stream;
+            let mut sample_interval =
+                tokio::time::interval(Duration::from_secs(SAMPLE_PERIOD_SECS as u64));
+            // the first tick completes immediately
+            sample_interval.tick().await;
+
+            let mut counters = SampleCounters::default();
+            let mut processed_slot: Option<Slot> = None;
+            let mut sample_start_slot: Option<Slot> = None;
+            loop {
+                tokio::select! {
+                    block = block_stream.recv() => {
+                        match block {
+                            Ok(block) => {
+                                // confirmed blocks are only notified once and never on a minority fork
+                                if !block.commitment_config.is_confirmed() {
+                                    continue;
+                                }
+                                for tx in &block.transactions {
+                                    counters.num_transactions += 1;
+                                    if !tx.is_vote() {
+                                        counters.num_non_vote_transactions += 1;
+                                    }
+                                }
+                            }
+                            Err(RecvError::Lagged(nb_blocks)) => {
+                                warn!("Performance samples lagged {nb_blocks} blocks behind");
+                            }
+                            Err(RecvError::Closed) => {
+                                bail!("Block stream closed for performance samples");
+                            }
+                        }
+                    }
+                    slot_notification = slot_stream.recv() => {
+                        match slot_notification {
+                            Ok(slot_notification) => {
+                                processed_slot = Some(slot_notification.processed_slot);
+                                sample_start_slot.get_or_insert(slot_notification.processed_slot);
+                            }
+                            Err(RecvError::Lagged(_)) => continue,
+                            Err(RecvError::Closed) => {
+                                bail!("Slot stream closed for performance samples");
+                            }
+                        }
+                    }
+                    _ = sample_interval.tick() => {
+                        let sample_counters = std::mem::take(&mut counters);
+                        // no slot seen during the first period
+                        let (Some(slot), Some(start_slot)) = (processed_slot, sample_start_slot) else {
+                            continue;
+                        };
+                        performance_samples
+                            .add_sample(RpcPerfSample {
+                                slot,
+                                num_transactions: sample_counters.num_transactions,
+                                num_non_vote_transactions: Some(sample_counters.num_non_vote_transactions),
+                                num_slots: slot.saturating_sub(start_slot),
+                                sample_period_secs: SAMPLE_PERIOD_SECS,
+                            })
+                            .await;
+                        sample_start_slot = Some(slot);
+                    }
+                }
+            }
+        })
+    }
+}
@@ -118,12 +118,13 @@ impl QuicConnection {
         }
     }
 
-    pub async fn send_transaction(&self, tx: Vec<u8>) {
+    /// Returns true when the transaction has been written to the connection
+    pub async fn send_transaction(&self, tx: Vec<u8>) -> bool {
         let connection_retry_count = self.connection_params.connection_retry_count;
         for _ in 0..connection_retry_count {
             if self.exit_signal.load(Ordering::Relaxed) {
                 // return
-                return;
+                return false;
             }
 
             let mut do_retry = false;
@@ -149,6 +150,7 @@ impl QuicConnection {
                         {
                             Ok(()) => {
                                 SEND_TRANSCTION_SUCESSFUL.inc();
+                                return true;
                             }
                             Err(QuicConnectionError::ConnectionError { retry }) => {
                                 do_retry = retry;
@@ -182,6 +184,7 @@ impl QuicConnection {
                 break;
             }
         }
+        false
     }
 
     pub fn get_timeout_count(&self) -> u64 {
@@ -6,7 +6,7 @@ use solana_lite_rpc_core::{
     stores::data_cache::DataCache,
     structures::{
         identity_stakes::IdentityStakesData, rotating_queue::RotatingQueue,
-        transaction_sent_info::SentTransactionInfo,
+        transaction_lifecycle::QuicSendOutcome, transaction_sent_info::SentTransactionInfo,
     },
 };
 use solana_sdk::pubkey::Pubkey;
@@ -109,13 +109,13 @@ impl ActiveConnection {
                         break;
                     }
 
-                    let tx: Vec<u8> = match tx {
+                    let (signature, tx, forward_index): (String, Vec<u8>, Option<usize>) = match tx {
                         Ok(transaction_sent_info) => {
                             if self.data_cache.txs.is_transaction_confirmed(&transaction_sent_info.signature) {
                                 // transaction is already confirmed/ no need to send
                                 continue;
                             }
-                            transaction_sent_info.transaction
+                            (transaction_sent_info.signature, transaction_sent_info.transaction, transaction_sent_info.forward_index)
                         },
                         Err(e) => {
                             error!(
@@ -137,11 +137,19 @@ impl ActiveConnection {
                         },
                     };
 
+                    let tx_lifecycles = self.data_cache.tx_lifecycles.clone();
                     tokio::spawn(async move {
                         // permit will be used to send all the transaction and then destroyed
                         let _permit = permit;
                         NB_QUIC_TASKS.inc();
-                        connection.send_transaction(tx).await;
+                        let outcome = if connection.send_transaction(tx).await {
+                            QuicSendOutcome::Sent
+                        } else {
+                            QuicSendOutcome::Failed
+                        };
+                        if let Some(forward_index) = forward_index {
+                            tx_lifecycles.quic_outcome(&signature, forward_index, identity, outcome);
+                        }
                         NB_QUIC_TASKS.dec();
                     });
                 },
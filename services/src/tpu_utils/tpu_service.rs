@@ -13,11 +13,12 @@ use solana_lite_rpc_core::structures::transaction_sent_info::SentTransactionInfo
 use solana_lite_rpc_core::traits::leaders_fetcher_interface::LeaderFetcherInterface;
 use solana_lite_rpc_core::types::SlotStream;
 use solana_lite_rpc_core::AnyhowJoinHandle;
-use solana_sdk::{quic::QUIC_PORT_OFFSET, signature::Keypair, slot_history::Slot};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, slot_history::Slot};
 use solana_streamer::tls_certificates::new_self_signed_tls_certificate;
 use std::{
+    collections::HashMap,
     net::{IpAddr, Ipv4Addr},
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 
 lazy_static::lazy_static! {
@@ -49,6 +50,8 @@ pub struct TpuService {
     leader_schedule: Arc<dyn LeaderFetcherInterface>,
     config: TpuServiceConfig,
     data_cache: DataCache,
+    // leaders the transactions are currently forwarded to
+    current_leaders: Arc<RwLock<Vec<Pubkey>>>,
 }
 
 #[derive(Clone)]
@@ -103,6 +106,7 @@ impl TpuService {
             connection_manager,
             config,
             data_cache,
+            current_leaders: Arc::new(RwLock::new(vec![])),
         })
     }
 
@@ -111,6 +115,14 @@ impl TpuService {
         Ok(())
     }
 
+    /// leaders with a tpu address the transactions are currently forwarded to
+    pub fn current_leaders(&self) -> Vec<Pubkey> {
+        self.current_leaders
+            .read()
+            .expect("current leaders lock poisoned")
+            .clone()
+    }
+
     // update/reconfigure connections on slot change
     async fn update_quic_connections(
         &self,
@@ -120,8 +132,6 @@ impl TpuService {
         let fanout = self.config.fanout_slots;
         let last_slot = estimated_slot + fanout;
 
-        let cluster_nodes = self.data_cache.cluster_info.cluster_nodes.clone();
-
         let next_leaders = self
             .leader_schedule
             .get_slot_leaders(current_slot, last_slot)
@@ -129,22 +139,18 @@ impl TpuService {
         // get next leader with its tpu port
         let connections_to_keep = next_leaders
             .iter()
-            .map(|x| {
-                let contact_info = cluster_nodes.get(&x.pubkey);
-                let tpu_port = match contact_info {
-                    Some(info) => info.tpu,
-                    _ => None,
-                };
-                (x.pubkey, tpu_port)
-            })
-            .filter(|x| x.1.is_some())
-            .map(|x| {
-                let mut addr = x.1.unwrap();
-                // add quic port offset
-                addr.set_port(addr.port() + QUIC_PORT_OFFSET);
-                (x.0, addr)
+            .filter_map(|x| {
+                self.data_cache
+                    .cluster_info
+                    .get_tpu_quic_address(&x.pubkey)
+                    .map(|addr| (x.pubkey, addr))
             })
-            .collect();
+            .collect::<HashMap<_, _>>();
+        *self
+            .current_leaders
+            .write()
+            .expect("current leaders lock poisoned") =
+            connections_to_keep.keys().copied().collect();
 
         match &self.connection_manager {
             DirectTpu {
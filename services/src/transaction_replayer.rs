@@ -3,7 +3,11 @@ use anyhow::{bail, Context};
 use log::error;
 use prometheus::{core::GenericGauge, opts, register_int_gauge};
 use solana_lite_rpc_core::{
-    stores::tx_store::TxStore, structures::transaction_sent_info::SentTransactionInfo,
+    stores::data_cache::DataCache,
+    structures::{
+        transaction_lifecycle::TransactionLifecycleStatus,
+        transaction_sent_info::SentTransactionInfo,
+    },
     AnyhowJoinHandle,
 };
 use std::time::Duration;
@@ -34,15 +38,15 @@ pub struct TransactionReplay {
 #[derive(Clone)]
 pub struct TransactionReplayer {
     pub tpu_service: TpuService,
-    pub tx_store: TxStore,
+    pub data_cache: DataCache,
     pub retry_offset: Duration,
 }
 
 impl TransactionReplayer {
-    pub fn new(tpu_service: TpuService, tx_store: TxStore, retry_offset: Duration) -> Self {
+    pub fn new(tpu_service: TpuService, data_cache: DataCache, retry_offset: Duration) -> Self {
         Self {
             tpu_service,
-            tx_store,
+            data_cache,
             retry_offset,
         }
     }
@@ -53,7 +57,7 @@ impl TransactionReplayer {
         mut reciever: UnboundedReceiver<TransactionReplay>,
     ) -> AnyhowJoinHandle {
         let tpu_service = self.tpu_service.clone();
-        let tx_store = self.tx_store.clone();
+        let data_cache = self.data_cache.clone();
         let retry_offset = self.retry_offset;
 
         tokio::spawn(async move {
@@ -69,7 +73,7 @@ impl TransactionReplayer {
                     }
                     tokio::time::sleep_until(tx_replay.replay_at).await;
                 }
-                if let Some(tx) = tx_store.get(&tx_replay.transaction.signature) {
+                if let Some(tx) = data_cache.txs.get(&tx_replay.transaction.signature) {
                     if tx.status.is_some() {
                         // transaction has been confirmed / no retry needed
                         continue;
@@ -79,7 +83,26 @@ impl TransactionReplayer {
                     continue;
                 }
                 // ignore reset error
-                let _ = tpu_service.send_transaction(&tx_replay.transaction);
+                let replayed_slot = data_cache.slot_cache.get_current_slot();
+                let send_result = data_cache.tx_lifecycles.forward(
+                    &tx_replay.transaction.signature,
+                    replayed_slot,
+                    tpu_service.current_leaders(),
+                    true,
+                    |forward_index| {
+                        tpu_service.send_transaction(&SentTransactionInfo {
+                            forward_index,
+                            ..tx_replay.transaction.clone()
+                        })
+                    },
+                );
+                if send_result.is_ok() {
+                    data_cache.tx_subs.notify_transaction_lifecycle(
+                        &tx_replay.transaction.signature,
+                        TransactionLifecycleStatus::Replayed,
+                        replayed_slot,
+                    );
+                }
 
                 if tx_replay.replay_count < tx_replay.max_replay {
                     tx_replay.replay_count += 1;
@@ -14,7 +14,10 @@ use solana_lite_rpc_core::{
     types::SlotStream,
 };
 use solana_lite_rpc_core::{
-    stores::block_information_store::{BlockInformation, BlockInformationStore},
+    stores::{
+        block_information_store::{BlockInformation, BlockInformationStore},
+        tx_lifecycle_store::TxLifecycleStore,
+    },
     structures::notifications::NotificationSender,
     AnyhowJoinHandle,
 };
@@ -51,6 +54,7 @@ impl TransactionServiceBuilder {
         self,
         notifier: Option<NotificationSender>,
         block_information_store: BlockInformationStore,
+        tx_lifecycles: TxLifecycleStore,
         max_retries: usize,
         slot_notifications: SlotStream,
     ) -> (TransactionService, AnyhowJoinHandle) {
@@ -90,6 +94,7 @@ impl TransactionServiceBuilder {
                 transaction_channel,
                 replay_channel,
                 block_information_store,
+                tx_lifecycles,
                 max_retries,
                 replay_offset: self.tx_replayer.retry_offset,
             },
@@ -103,6 +108,7 @@ pub struct TransactionService {
     pub transaction_channel: Sender<SentTransactionInfo>,
     pub replay_channel: UnboundedSender<TransactionReplay>,
     pub block_information_store: BlockInformationStore,
+    pub tx_lifecycles: TxLifecycleStore,
     pub max_retries: usize,
     pub replay_offset: Duration,
 }
@@ -138,7 +144,13 @@ impl TransactionService {
             last_valid_block_height: last_valid_blockheight,
             slot,
             transaction: raw_tx,
+            forward_index: None,
         };
+        self.tx_lifecycles.received(
+            transaction_info.signature.clone(),
+            last_valid_blockheight,
+            max_replay,
+        );
         if let Err(e) = self
             .transaction_channel
             .send(transaction_info.clone())
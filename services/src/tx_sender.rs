@@ -15,6 +15,7 @@ use solana_lite_rpc_core::{
     stores::{data_cache::DataCache, tx_store::TxProps},
     structures::{
         notifications::{NotificationMsg, NotificationSender, TransactionNotification},
+        transaction_lifecycle::TransactionLifecycleStatus,
         transaction_sent_info::SentTransactionInfo,
     },
     AnyhowJoinHandle,
@@ -87,9 +88,31 @@ impl TxSender {
                 },
             );
 
-            let quic_response = match tpu_client.send_transaction(transaction_info) {
+            // the forward is recorded before the received notification for the subscriptions
+            // made meanwhile
+            let send_result = self.data_cache.tx_lifecycles.forward(
+                &transaction_info.signature,
+                forwarded_slot,
+                tpu_client.current_leaders(),
+                false,
+                |forward_index| {
+                    tpu_client.send_transaction(&SentTransactionInfo {
+                        forward_index,
+                        ..transaction_info.clone()
+                    })
+                },
+            );
+            let quic_response = match send_result {
                 Ok(_) => {
                     TXS_SENT.inc_by(1);
+                    self.data_cache
+                        .tx_subs
+                        .notify_received(&transaction_info.signature, forwarded_slot);
+                    self.data_cache.tx_subs.notify_transaction_lifecycle(
+                        &transaction_info.signature,
+                        TransactionLifecycleStatus::Forwarded,
+                        forwarded_slot,
+                    );
                     1
                 }
                 Err(err) => {
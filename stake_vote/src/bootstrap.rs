@@ -1,5 +1,6 @@
 use crate::epoch::ScheduleEpochData;
 use crate::leader_schedule::LeaderScheduleGeneratedData;
+use crate::snapshot::EpochSnapshot;
 use crate::stake::StakeMap;
 use crate::stake::StakeStore;
 use crate::utils::{Takable, TakeResult};
@@ -27,12 +28,6 @@ use std::collections::HashMap;
 use std::time::Duration;
 use tokio::task::JoinHandle;
 
-//File where the Vote and stake use to calculate the leader schedule at epoch are stored.
-//Use to bootstrap current and next epoch leader schedule.
-//TODO to be removed with inter RPC bootstrap and snapshot read.
-pub const CURRENT_EPOCH_VOTE_STAKES_FILE: &str = "current_vote_stakes.json";
-pub const NEXT_EPOCH_VOTE_STAKES_FILE: &str = "next_vote_stakes.json";
-
 pub async fn bootstrap_schedule_epoch_data(data_cache: &DataCache) -> ScheduleEpochData {
     let new_rate_activation_epoch = solana_sdk::feature_set::FeatureSet::default()
         .new_warmup_cooldown_rate_epoch(data_cache.epoch_data.get_epoch_schedule());
@@ -49,20 +44,24 @@ pub async fn bootstrap_schedule_epoch_data(data_cache: &DataCache) -> ScheduleEp
     )
 }
 
-// Return the current and next epoxh leader schedule and the current epoch stakes of vote accounts
-// if the corresponding files exist.
-pub fn bootstrap_leaderschedule_from_files(
+// Return the current and next epoch leader schedule and the current epoch stakes of vote accounts
+// if a snapshot of the current epoch exists.
+pub fn bootstrap_leaderschedule_from_snapshot(
+    snapshot_file: &str,
     current_epoch_of_loading: u64,
-    slots_in_epoch: u64,
 ) -> Option<(CalculatedSchedule, RpcVoteAccountStatus)> {
-    bootstrap_current_leader_schedule(slots_in_epoch, current_epoch_of_loading)
-        .map(|(leader_schedule, current_epoch_stakes, _)| {
+    match EpochSnapshot::load(snapshot_file, current_epoch_of_loading) {
+        Ok(snapshot) => {
             let vote_acccounts = crate::vote::get_rpc_vote_account_info_from_current_epoch_stakes(
-                &current_epoch_stakes,
+                &snapshot.epoch_vote_stakes,
             );
-            (leader_schedule, vote_acccounts)
-        })
-        .ok()
+            Some((snapshot.schedule, vote_acccounts))
+        }
+        Err(err) => {
+            log::info!("No stake and vote snapshot loaded:{err}");
+            None
+        }
+    }
 }
 
 // Return the current or next epoch leader schedule using the RPC calls.
@@ -127,7 +126,11 @@ Bootstrap state changes
 
   InitBootstrap
        |
-  |Fetch accounts|
+  |Load snapshot of the current epoch|
+       |                         |
+  SnapshotLoaded(snapshot)   |Fetch accounts|
+       |
+  |Extract stores, merge snapshot| -> AccountsMerged(stores)
 
     |          |
   Error   BootstrapAccountsFetched(account list)
@@ -154,15 +157,15 @@ pub fn run_bootstrap_events(
     bootstrap_tasks: &mut FuturesUnordered<JoinHandle<BootstrapEvent>>,
     stakestore: &mut StakeStore,
     votestore: &mut VoteStore,
-    slots_in_epoch: u64,
-    current_epoch_of_loading: u64,
+    current_schedule_epoch: &ScheduleEpochData,
+    snapshot_file: &str,
 ) -> anyhow::Result<Option<anyhow::Result<(CalculatedSchedule, RpcVoteAccountStatus)>>> {
     let result = process_bootstrap_event(
         event,
         stakestore,
         votestore,
-        slots_in_epoch,
-        current_epoch_of_loading,
+        current_schedule_epoch,
+        snapshot_file,
     );
     match result {
         BootsrapProcessResult::TaskHandle(jh) => {
@@ -174,8 +177,8 @@ pub fn run_bootstrap_events(
             bootstrap_tasks,
             stakestore,
             votestore,
-            slots_in_epoch,
-            current_epoch_of_loading,
+            current_schedule_epoch,
+            snapshot_file,
         ),
         BootsrapProcessResult::End(leader_schedule_result) => Ok(Some(leader_schedule_result)),
         BootsrapProcessResult::Error(err) => bail!(err),
@@ -187,6 +190,7 @@ pub enum BootstrapEvent {
         sleep_time: u64,
         rpc_url: String,
     },
+    SnapshotLoaded(EpochSnapshot, String),
     BootstrapAccountsFetched(
         Vec<(Pubkey, Account)>,
         Vec<(Pubkey, Account)>,
@@ -224,18 +228,28 @@ fn process_bootstrap_event(
     event: BootstrapEvent,
     stakestore: &mut StakeStore,
     votestore: &mut VoteStore,
-    slots_in_epoch: u64,
-    current_epoch_of_loading: u64,
+    current_schedule_epoch: &ScheduleEpochData,
+    snapshot_file: &str,
 ) -> BootsrapProcessResult {
+    let current_epoch_of_loading = current_schedule_epoch.current_epoch;
     match event {
         BootstrapEvent::InitBootstrap {
             sleep_time,
             rpc_url,
         } => {
+            let snapshot_file = snapshot_file.to_string();
             let jh = tokio::task::spawn_blocking(move || {
                 if sleep_time > 0 {
                     std::thread::sleep(Duration::from_secs(sleep_time));
                 }
+                //restart from the snapshot of the current epoch to avoid fetching all accounts.
+                match EpochSnapshot::load(&snapshot_file, current_epoch_of_loading) {
+                    Ok(snapshot) => {
+                        log::info!("Bootstrap from the snapshot of epoch:{}", snapshot.epoch);
+                        return BootstrapEvent::SnapshotLoaded(snapshot, rpc_url);
+                    }
+                    Err(err) => log::info!("No stake and vote snapshot loaded:{err}"),
+                }
                 match bootstrap_accounts(rpc_url.clone()) {
                     Ok((stakes, votes, history)) => {
                         BootstrapEvent::BootstrapAccountsFetched(stakes, votes, history, rpc_url)
@@ -250,6 +264,51 @@ fn process_bootstrap_event(
             });
             BootsrapProcessResult::TaskHandle(jh)
         }
+        BootstrapEvent::SnapshotLoaded(snapshot, rpc_url) => {
+            match (&mut stakestore.stakes, &mut votestore.votes).take() {
+                TakeResult::Map((mut stake_map, (mut vote_map, mut epoch_cache))) => {
+                    let jh = tokio::task::spawn_blocking(move || {
+                        let EpochSnapshot {
+                            stakes,
+                            epoch_vote_stakes,
+                            schedule,
+                            ..
+                        } = snapshot;
+                        crate::stake::merge_snapshot_in_stake_map(&mut stake_map, stakes);
+                        crate::vote::merge_epoch_vote_stakes_in_vote_map(
+                            &mut vote_map,
+                            &epoch_vote_stakes,
+                        );
+                        let vote_acccounts =
+                            crate::vote::get_rpc_vote_account_info_from_current_epoch_stakes(
+                                &epoch_vote_stakes,
+                            );
+                        epoch_cache.add_stakes_for_epoch(epoch_vote_stakes);
+                        BootstrapEvent::AccountsMerged(
+                            stake_map,
+                            vote_map,
+                            epoch_cache,
+                            rpc_url,
+                            Ok((schedule, vote_acccounts)),
+                        )
+                    });
+                    BootsrapProcessResult::TaskHandle(jh)
+                }
+                TakeResult::Taken(stake_notify) => {
+                    let notif_jh = tokio::spawn({
+                        async move {
+                            let notifs = stake_notify
+                                .iter()
+                                .map(|n| n.notified())
+                                .collect::<Vec<tokio::sync::futures::Notified>>();
+                            join_all(notifs).await;
+                            BootstrapEvent::SnapshotLoaded(snapshot, rpc_url)
+                        }
+                    });
+                    BootsrapProcessResult::TaskHandle(notif_jh)
+                }
+            }
+        }
         BootstrapEvent::BootstrapAccountsFetched(stakes, votes, history, rpc_url) => {
             match (&mut stakestore.stakes, &mut votestore.votes).take() {
                 TakeResult::Map((stake_map, (vote_map, epoch_cache))) => {
@@ -290,12 +349,14 @@ fn process_bootstrap_event(
             history,
             rpc_url,
         ) => {
-            let stake_history = crate::account::read_historystake_from_account(&history.data);
-            if stake_history.is_none() {
+            let Some(stake_history) = crate::account::read_historystake_from_account(&history.data)
+            else {
                 return BootsrapProcessResult::Error(
                     "Bootstrap error, can't read stake history from account data.".to_string(),
                 );
-            }
+            };
+            let slots_in_epoch = current_schedule_epoch.slots_in_epoch;
+            let new_rate_activation_epoch = current_schedule_epoch.new_rate_activation_epoch;
 
             //merge new PA with stake map and vote map in a specific task
             let jh = tokio::task::spawn_blocking({
@@ -312,33 +373,55 @@ fn process_bootstrap_event(
                         0, //with RPC no way to know the slot of the account update. Set to 0.
                     );
 
-                    match bootstrap_current_leader_schedule(
-                        current_epoch_of_loading,
+                    //without snapshot the current epoch stakes and the next epoch schedule are calculated from the RPC accounts.
+                    let current_epoch_stakes = EpochVoteStakes {
+                        vote_stakes: crate::leader_schedule::calculate_epoch_stakes(
+                            &stake_map,
+                            &vote_map,
+                            current_epoch_of_loading,
+                            &stake_history,
+                            new_rate_activation_epoch,
+                        ),
+                        epoch: current_epoch_of_loading,
+                    };
+                    let next_epoch = current_epoch_of_loading + 1;
+                    let next_schedule = crate::leader_schedule::calculate_leader_schedule(
+                        &current_epoch_stakes.vote_stakes,
+                        next_epoch,
                         slots_in_epoch,
-                    ) {
-                        Ok((leader_schedule, current_epoch_stakes, next_epoch_stakes)) => {
-                            let vote_acccounts =
-                                crate::vote::get_rpc_vote_account_info_from_current_epoch_stakes(
-                                    &current_epoch_stakes,
-                                );
-                            epoch_cache.add_stakes_for_epoch(current_epoch_stakes);
-                            epoch_cache.add_stakes_for_epoch(next_epoch_stakes);
-                            BootstrapEvent::AccountsMerged(
-                                stake_map,
-                                vote_map,
-                                epoch_cache,
-                                rpc_url,
-                                Ok((leader_schedule, vote_acccounts)),
-                            )
-                        }
-                        Err(err) => BootstrapEvent::AccountsMerged(
-                            stake_map,
-                            vote_map,
-                            epoch_cache,
-                            rpc_url,
-                            Err(err),
+                    );
+                    let next_schedule = LeaderScheduleData {
+                        schedule_by_node: LeaderScheduleGeneratedData::get_schedule_by_nodes(
+                            &next_schedule,
                         ),
-                    }
+                        schedule_by_slot: next_schedule.get_slot_leaders().to_vec(),
+                        epoch: next_epoch,
+                    };
+                    let vote_acccounts =
+                        crate::vote::get_rpc_vote_account_info_from_current_epoch_stakes(
+                            &current_epoch_stakes,
+                        );
+                    epoch_cache.add_stakes_for_epoch(current_epoch_stakes);
+
+                    //the current epoch schedule was calculated with the previous epoch stakes, it's taken from the RPC.
+                    let leader_schedule_result =
+                        get_rpc_current_leader_schedule(rpc_url.clone(), current_epoch_of_loading)
+                            .map(|current_schedule| {
+                                (
+                                    CalculatedSchedule {
+                                        current: Some(current_schedule),
+                                        next: Some(next_schedule),
+                                    },
+                                    vote_acccounts,
+                                )
+                            });
+                    BootstrapEvent::AccountsMerged(
+                        stake_map,
+                        vote_map,
+                        epoch_cache,
+                        rpc_url,
+                        leader_schedule_result,
+                    )
                 }
             });
             BootsrapProcessResult::TaskHandle(jh)
@@ -413,6 +496,26 @@ pub fn get_stakehistory_account(rpc_url: String) -> Result<Account, ClientError>
     rpc_client.get_account(&solana_sdk::sysvar::stake_history::id())
 }
 
+fn get_rpc_current_leader_schedule(
+    rpc_url: String,
+    current_epoch: u64,
+) -> anyhow::Result<LeaderScheduleData> {
+    let schedule_by_node = get_rpc_leader_schedule(rpc_url, None)?
+        .ok_or_else(|| anyhow::anyhow!("RPC return no leader schedule"))?;
+    //Calculate the slot leaders by from the node schedule because RPC call get_slot_leaders is limited to 5000 slots.
+    let schedule_by_slot = crate::leader_schedule::calculate_slot_leaders_from_schedule(
+        &schedule_by_node,
+    )
+    .map_err(|err| {
+        anyhow::anyhow!("Leader schedule from RPC can't generate slot leaders because:{err}")
+    })?;
+    Ok(LeaderScheduleData {
+        schedule_by_node,
+        schedule_by_slot,
+        epoch: current_epoch,
+    })
+}
+
 fn get_rpc_epoch_info(rpc_url: String) -> Result<EpochInfo, ClientError> {
     let rpc_client = RpcClient::new_with_timeout_and_commitment(
         rpc_url.clone(),
@@ -433,70 +536,3 @@ fn get_rpc_leader_schedule(
     );
     rpc_client.get_leader_schedule(slot)
 }
-
-// pub struct BootstrapScheduleResult {
-//     schedule: CalculatedSchedule,
-//     vote_stakes: Vec<EpochVoteStakes>,
-// }
-
-pub fn bootstrap_current_leader_schedule(
-    current_epoch_of_loading: u64,
-    slots_in_epoch: u64,
-) -> anyhow::Result<(CalculatedSchedule, EpochVoteStakes, EpochVoteStakes)> {
-    let (current_epoch, current_epoch_stakes) =
-        crate::utils::read_schedule_vote_stakes(CURRENT_EPOCH_VOTE_STAKES_FILE)?;
-    let (next_epoch, next_epoch_stakes) =
-        crate::utils::read_schedule_vote_stakes(NEXT_EPOCH_VOTE_STAKES_FILE)?;
-
-    //verify that the current loaded epoch correspond to the current epoch slot
-    if current_epoch_of_loading != current_epoch {
-        return Err(ClientError {
-            request: None,
-            kind: ClientErrorKind::Custom(
-                "Current epoch bootstrap file doesn't correspond to the validator current epoch."
-                    .to_string(),
-            ),
-        }
-        .into());
-    }
-
-    //calcualte leader schedule for all vote stakes.
-    let current_schedule = crate::leader_schedule::calculate_leader_schedule(
-        &current_epoch_stakes,
-        current_epoch,
-        slots_in_epoch,
-    );
-
-    let next_schedule = crate::leader_schedule::calculate_leader_schedule(
-        &next_epoch_stakes,
-        next_epoch,
-        slots_in_epoch,
-    );
-
-    Ok((
-        CalculatedSchedule {
-            current: Some(LeaderScheduleData {
-                schedule_by_node: LeaderScheduleGeneratedData::get_schedule_by_nodes(
-                    &current_schedule,
-                ),
-                schedule_by_slot: current_schedule.get_slot_leaders().to_vec(),
-                epoch: current_epoch,
-            }),
-            next: Some(LeaderScheduleData {
-                schedule_by_node: LeaderScheduleGeneratedData::get_schedule_by_nodes(
-                    &next_schedule,
-                ),
-                schedule_by_slot: next_schedule.get_slot_leaders().to_vec(),
-                epoch: next_epoch,
-            }),
-        },
-        EpochVoteStakes {
-            epoch: current_epoch,
-            vote_stakes: current_epoch_stakes,
-        },
-        EpochVoteStakes {
-            epoch: next_epoch,
-            vote_stakes: next_epoch_stakes,
-        },
-    ))
-}
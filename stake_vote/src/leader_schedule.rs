@@ -138,31 +138,6 @@ fn process_leadershedule_event(
                                 slots_in_epoch,
                             );
 
-                            if std::path::Path::new(crate::bootstrap::NEXT_EPOCH_VOTE_STAKES_FILE)
-                                .exists()
-                            {
-                                if let Err(err) = std::fs::rename(
-                                    crate::bootstrap::NEXT_EPOCH_VOTE_STAKES_FILE,
-                                    crate::bootstrap::CURRENT_EPOCH_VOTE_STAKES_FILE,
-                                ) {
-                                    log::error!(
-                                    "Fail to rename current leader schedule on disk because :{err}"
-                                );
-                                }
-                            }
-
-                            //save new vote stake in a file for bootstrap.
-                            if let Err(err) = crate::utils::save_schedule_vote_stakes(
-                                crate::bootstrap::NEXT_EPOCH_VOTE_STAKES_FILE,
-                                &epoch_vote_stakes,
-                                next_epoch,
-                            ) {
-                                log::error!(
-                                    "Error during saving the new leader schedule of epoch:{} in a file error:{err}",
-                                    next_epoch
-                                );
-                            }
-
                             epoch_cache.add_stakes_for_epoch(EpochVoteStakes {
                                 epoch: new_epoch,
                                 vote_stakes: epoch_vote_stakes,
@@ -244,7 +219,7 @@ fn process_leadershedule_event(
     }
 }
 
-fn calculate_epoch_stakes(
+pub fn calculate_epoch_stakes(
     stake_map: &StakeMap,
     vote_map: &VoteMap,
     new_epoch: u64,
@@ -3,16 +3,19 @@ use crate::bootstrap::BootstrapEvent;
 use futures::Stream;
 use futures_util::stream::FuturesUnordered;
 use futures_util::StreamExt;
+use geyser_grpc_connector::grpc_subscription_autoreconnect::GrpcSourceConfig;
 use solana_lite_rpc_core::stores::block_information_store::BlockInformation;
 use solana_lite_rpc_core::stores::data_cache::DataCache;
-use solana_lite_rpc_core::structures::leaderschedule::GetVoteAccountsConfig;
+use solana_lite_rpc_core::structures::leaderschedule::{GetVoteAccountsConfig, LeaderScheduleData};
 use solana_lite_rpc_core::types::SlotStream;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::response::RpcVoteAccountStatus;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc::Receiver;
 use yellowstone_grpc_client::GeyserGrpcClient;
 use yellowstone_grpc_proto::geyser::CommitmentLevel;
@@ -26,28 +29,34 @@ mod bootstrap;
 mod epoch;
 mod leader_schedule;
 mod rpcrequest;
+mod snapshot;
 mod stake;
 mod utils;
+mod verify_schedule;
 mod vote;
 
-// pub use bootstrap::{bootstrap_leaderschedule_from_files, bootstrap_leaderschedule_from_rpc};
+// pub use bootstrap::{bootstrap_leaderschedule_from_snapshot, bootstrap_leaderschedule_from_rpc};
 
 const STAKESTORE_INITIAL_CAPACITY: usize = 600000;
 const VOTESTORE_INITIAL_CAPACITY: usize = 600000;
 
+//delays before subscribing again to geyser after a failure, doubled after each consecutive failure.
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
 type Slot = u64;
 
 pub async fn bootstrat_literpc_leader_schedule(
     rpc_url: String,
     data_cache: &DataCache,
     current_epoch_of_loading: u64,
+    snapshot_file: &str,
 ) {
     //init leader schedule grpc process.
-    //1) get stored schedule and stakes
-    let slots_per_epoch = data_cache.epoch_data.get_epoch_schedule().slots_per_epoch;
-    match crate::bootstrap::bootstrap_leaderschedule_from_files(
+    //1) get the schedule and stakes of the epoch snapshot
+    match crate::bootstrap::bootstrap_leaderschedule_from_snapshot(
+        snapshot_file,
         current_epoch_of_loading,
-        slots_per_epoch,
     ) {
         Some((leader_schedule, vote_stakes)) => {
             data_cache
@@ -58,7 +67,7 @@ pub async fn bootstrat_literpc_leader_schedule(
             *data_schedule = leader_schedule;
         }
         None => {
-            log::info!("Leader schedule bootstrap file not found. Try to boot from rpc.");
+            log::info!("Leader schedule snapshot not found. Try to boot from rpc.");
             match crate::bootstrap::bootstrap_leaderschedule_from_rpc(
                 rpc_url,
                 data_cache.epoch_data.get_epoch_schedule(),
@@ -84,14 +93,21 @@ pub async fn start_stakes_and_votes_loop(
     mut slot_notification: SlotStream,
     mut vote_account_rpc_request: Receiver<(
         GetVoteAccountsConfig,
-        tokio::sync::oneshot::Sender<RpcVoteAccountStatus>,
+        tokio::sync::oneshot::Sender<Option<RpcVoteAccountStatus>>,
     )>,
     rpc_client: Arc<RpcClient>,
-    grpc_url: String,
+    // vote accounts RPC polling flag, cleared once the current epoch stakes are computed from geyser
+    poll_vote_accounts: Arc<AtomicBool>,
+    grpc_source: GrpcSourceConfig,
+    // when set, calculated leader schedules are verified with the rpc before being used
+    max_leader_schedule_mismatch_percent: Option<f64>,
+    // file where the stakes, vote stakes and leader schedule are saved at each epoch change
+    // to bootstrap after a restart without fetching all stake accounts from the RPC
+    snapshot_file: String,
 ) -> anyhow::Result<tokio::task::JoinHandle<()>> {
-    log::info!("Start Stake and Vote loop on :{grpc_url}.");
-    let mut stake_vote_geyser_stream = subscribe_geyser_stake_vote_owner(grpc_url.clone()).await?;
-    let mut stake_history_geyser_stream = subscribe_geyser_stake_history(grpc_url).await?;
+    log::info!("Start Stake and Vote loop on :{}.", grpc_source.grpc_addr);
+    let mut stake_vote_geyser_stream = subscribe_geyser_stake_vote_owner(&grpc_source).await?;
+    let mut stake_history_geyser_stream = subscribe_geyser_stake_history(&grpc_source).await?;
     log::info!("Stake and Vote geyser subscription done.");
     let jh = tokio::spawn(async move {
         //Stake account management struct
@@ -107,10 +123,12 @@ pub async fn start_stakes_and_votes_loop(
         //future execution collection.
         let mut spawned_leader_schedule_task = FuturesUnordered::new();
         let mut spawned_bootstrap_task = FuturesUnordered::new();
+        let mut spawned_verify_schedule_task = FuturesUnordered::new();
+        let rpc_url = rpc_client.url();
         let jh = tokio::spawn(async move {
             BootstrapEvent::InitBootstrap {
                 sleep_time: 1,
-                rpc_url: rpc_client.url(),
+                rpc_url,
             }
         });
         spawned_bootstrap_task.push(jh);
@@ -123,6 +141,9 @@ pub async fn start_stakes_and_votes_loop(
         let mut account_update_notification = None;
         let mut epoch_wait_account_notification_task = FuturesUnordered::new();
 
+        let mut stake_vote_retry_after = MIN_RETRY_DELAY;
+        let mut stake_history_retry_after = MIN_RETRY_DELAY;
+
         loop {
             tokio::select! {
                 //manage confirm new slot notification to detect epoch change.
@@ -181,6 +202,7 @@ pub async fn start_stakes_and_votes_loop(
                 ret = stake_history_geyser_stream.next() => {
                     match ret {
                         Some(Ok(msg)) => {
+                            stake_history_retry_after = MIN_RETRY_DELAY;
                             if let Some(UpdateOneof::Account(account))  = msg.update_oneof {
                                 if let Some(account) = account.account {
                                     let acc_id = Pubkey::try_from(account.pubkey).expect("valid pubkey");
@@ -207,9 +229,13 @@ pub async fn start_stakes_and_votes_loop(
                             }
                         },
                          None |  Some(Err(_))  => {
-                            //TODO Restart geyser connection and the bootstrap.
-                            log::error!("The stake_history geyser stream close or in error try to reconnect and resynchronize.");
-                            break;
+                            //a failed subscription keeps the closed stream, it's retried at the next poll.
+                            log::error!("The stake_history geyser stream close or in error, resubscribe in {stake_history_retry_after:?}.");
+                            wait_before_retry(&mut stake_history_retry_after).await;
+                            match subscribe_geyser_stake_history(&grpc_source).await {
+                                Ok(stream) => stake_history_geyser_stream = stream,
+                                Err(err) => log::error!("Stake history geyser subscription fail:{err}"),
+                            }
                          }
                     }
                 }
@@ -222,6 +248,7 @@ pub async fn start_stakes_and_votes_loop(
                             //process the message
                             match message {
                                 Ok(msg) => {
+                                    stake_vote_retry_after = MIN_RETRY_DELAY;
                                     match msg.update_oneof {
                                         Some(UpdateOneof::Account(account)) => {
                                             // log::info!("Stake and Vote geyser receive an account:{}.",
@@ -278,26 +305,32 @@ pub async fn start_stakes_and_votes_loop(
                             }
                          }
                          None => {
-                            //TODO Restart geyser connection and the bootstrap.
-                            log::error!("The geyser stream close try to reconnect and resynchronize.");
-                            break;
+                            //a failed subscription keeps the closed stream, it's retried at the next poll.
+                            //TODO resynchronize the account changes missed during the reconnection.
+                            log::error!("The geyser stream close, resubscribe in {stake_vote_retry_after:?}.");
+                            wait_before_retry(&mut stake_vote_retry_after).await;
+                            match subscribe_geyser_stake_vote_owner(&grpc_source).await {
+                                Ok(stream) => stake_vote_geyser_stream = stream,
+                                Err(err) => log::error!("Stake and vote geyser subscription fail:{err}"),
+                            }
                          }
                     }
                 }
                 //manage bootstrap event
                 Some(Ok(event)) = spawned_bootstrap_task.next() =>  {
-                    match crate::bootstrap::run_bootstrap_events(event, &mut spawned_bootstrap_task, &mut stakestore, &mut votestore, current_schedule_epoch.slots_in_epoch, current_schedule_epoch.current_epoch) {
+                    match crate::bootstrap::run_bootstrap_events(event, &mut spawned_bootstrap_task, &mut stakestore, &mut votestore, &current_schedule_epoch, &snapshot_file) {
                         Ok(Some(boot_res))=> {
                             match boot_res {
                                 Ok((current_schedule_data, vote_stakes)) => {
                                     data_cache
                                         .identity_stakes
                                         .update_stakes_for_identity(vote_stakes).await;
+                                    poll_vote_accounts.store(false, Ordering::Relaxed);
                                     let mut data_schedule = data_cache.leader_schedule.write().await;
                                     *data_schedule = current_schedule_data;
                                 }
                                 Err(err) => {
-                                    log::warn!("Error during current leader schedule bootstrap from files:{err}")
+                                    log::warn!("Error during current leader schedule bootstrap:{err}")
                                 }
                             }
                             log::info!("Bootstrap done.");
@@ -310,6 +343,17 @@ pub async fn start_stakes_and_votes_loop(
                         Err(err) => log::error!("Stake / Vote Account bootstrap fail because '{err}'"),
                     }
                 }
+                //Manage leader schedule verification process
+                Some((verify_result, calculated_schedule)) = spawned_verify_schedule_task.next() =>  {
+                    let leader_schedule = match verify_result {
+                        Ok(verified_leader_schedule) => verified_leader_schedule,
+                        Err(err) => {
+                            log::error!("Leader schedule verification of epoch:{} fail, calculated schedule used:{err}", calculated_schedule.epoch);
+                            calculated_schedule
+                        }
+                    };
+                    set_next_leader_schedule(&data_cache, &stakestore, &votestore, leader_schedule, &snapshot_file).await;
+                }
                 //Manage leader schedule generation process
                 Some(Ok(event)) = spawned_leader_schedule_task.next() =>  {
                     let new_leader_schedule = crate::leader_schedule::run_leader_schedule_events(
@@ -322,9 +366,40 @@ pub async fn start_stakes_and_votes_loop(
                         //clone old schedule values is there's other use.
                         //only done once epoch. Avoid to use a Mutex.
                         log::info!("End leader schedule calculus  for epoch:{}", new_leader_schedule.epoch);
-                        let mut data_schedule = data_cache.leader_schedule.write().await;
-                        data_schedule.current = data_schedule.next.take();
-                        data_schedule.next = Some(new_leader_schedule.rpc_data);
+                        match max_leader_schedule_mismatch_percent {
+                            Some(max_mismatch_percent) => {
+                                let first_slot_in_epoch = data_cache
+                                    .epoch_data
+                                    .get_epoch_schedule()
+                                    .get_first_slot_in_epoch(new_leader_schedule.epoch);
+                                let calculated_schedule = new_leader_schedule.rpc_data;
+                                let jh = tokio::spawn(crate::verify_schedule::verify_leader_schedule(
+                                    rpc_client.clone(),
+                                    first_slot_in_epoch,
+                                    calculated_schedule.clone(),
+                                    max_mismatch_percent,
+                                ));
+                                //the calculated schedule is kept to be used if the verification task fails.
+                                spawned_verify_schedule_task.push(async move { (jh.await, calculated_schedule) });
+                            }
+                            None => set_next_leader_schedule(&data_cache, &stakestore, &votestore, new_leader_schedule.rpc_data, &snapshot_file).await,
+                        }
+
+                        //the schedule of the next epoch is calculated with the stakes of the new current epoch.
+                        //update the identity stakes with them, vote accounts are no longer polled from the RPC.
+                        let current_epoch_vote_accounts = votestore
+                            .votes
+                            .content
+                            .as_ref()
+                            .and_then(|(_, epoch_cache)| epoch_cache.vote_stakes_for_epoch(new_leader_schedule.epoch - 1))
+                            .map(crate::vote::get_rpc_vote_account_info_from_current_epoch_stakes);
+                        if let Some(vote_accounts) = current_epoch_vote_accounts {
+                            data_cache
+                                .identity_stakes
+                                .update_stakes_for_identity(vote_accounts)
+                                .await;
+                            poll_vote_accounts.store(false, Ordering::Relaxed);
+                        }
                     }
 
                 }
@@ -334,11 +409,62 @@ pub async fn start_stakes_and_votes_loop(
     Ok(jh)
 }
 
+//the schedule of the next epoch becomes the current one.
+//the new epoch state is saved in a snapshot to restart from it.
+async fn set_next_leader_schedule(
+    data_cache: &DataCache,
+    stakestore: &stake::StakeStore,
+    votestore: &vote::VoteStore,
+    next_leader_schedule: LeaderScheduleData,
+    snapshot_file: &str,
+) {
+    let current_epoch = next_leader_schedule.epoch - 1;
+    let schedule = {
+        let mut data_schedule = data_cache.leader_schedule.write().await;
+        data_schedule.current = data_schedule.next.take();
+        data_schedule.next = Some(next_leader_schedule);
+        data_schedule.clone()
+    };
+
+    //stores are taken during bootstrap or get_vote_accounts processing, the snapshot is skipped.
+    let (Some(stakes), Some((_, epoch_cache))) = (
+        stakestore.stakes.content.as_ref(),
+        votestore.votes.content.as_ref(),
+    ) else {
+        log::warn!("Stake or vote store not available, no snapshot for epoch:{current_epoch}");
+        return;
+    };
+    let Some(epoch_vote_stakes) = epoch_cache.vote_stakes_for_epoch(current_epoch) else {
+        log::warn!("No vote stakes for epoch:{current_epoch}, no snapshot saved");
+        return;
+    };
+    let snapshot = crate::snapshot::EpochSnapshot {
+        epoch: current_epoch,
+        stakes: stakes.clone(),
+        epoch_vote_stakes: epoch_vote_stakes.clone(),
+        schedule,
+    };
+    let snapshot_file = snapshot_file.to_string();
+    tokio::task::spawn_blocking(move || match snapshot.save(&snapshot_file) {
+        Ok(()) => log::info!("Stake and vote snapshot of epoch:{current_epoch} saved"),
+        Err(err) => log::error!("Fail to save the snapshot of epoch:{current_epoch}:{err}"),
+    });
+}
+
+async fn wait_before_retry(retry_after: &mut Duration) {
+    tokio::time::sleep(*retry_after).await;
+    *retry_after = (*retry_after * 2).min(MAX_RETRY_DELAY);
+}
+
 //subscribe Geyser grpc
 async fn subscribe_geyser_stake_vote_owner(
-    grpc_url: String,
+    grpc_source: &GrpcSourceConfig,
 ) -> anyhow::Result<impl Stream<Item = Result<SubscribeUpdate, Status>>> {
-    let mut client = GeyserGrpcClient::connect(grpc_url, None::<&'static str>, None)?;
+    let mut client = GeyserGrpcClient::connect(
+        grpc_source.grpc_addr.clone(),
+        grpc_source.grpc_x_token.clone(),
+        None,
+    )?;
 
     //account subscription
     let mut accounts: HashMap<String, SubscribeRequestFilterAccounts> = HashMap::new();
@@ -373,9 +499,13 @@ async fn subscribe_geyser_stake_vote_owner(
 
 //subscribe Geyser grpc
 async fn subscribe_geyser_stake_history(
-    grpc_url: String,
+    grpc_source: &GrpcSourceConfig,
 ) -> anyhow::Result<impl Stream<Item = Result<SubscribeUpdate, Status>>> {
-    let mut client = GeyserGrpcClient::connect(grpc_url, None::<&'static str>, None)?;
+    let mut client = GeyserGrpcClient::connect(
+        grpc_source.grpc_addr.clone(),
+        grpc_source.grpc_x_token.clone(),
+        None,
+    )?;
 
     //account subscription
     let mut accounts: HashMap<String, SubscribeRequestFilterAccounts> = HashMap::new();
@@ -13,8 +13,8 @@ use tokio::task::JoinHandle;
 pub struct RpcRequestData {
     pub rpc_notify_task: FuturesUnordered<JoinHandle<(u64, u64, GetVoteAccountsConfig)>>,
     pub rpc_exec_task:
-        FuturesUnordered<JoinHandle<(VoteMap, EpochVoteStakesCache, RpcVoteAccountStatus)>>,
-    pending_rpc_request: Option<Vec<oneshot::Sender<RpcVoteAccountStatus>>>,
+        FuturesUnordered<JoinHandle<(VoteMap, EpochVoteStakesCache, Option<RpcVoteAccountStatus>)>>,
+    pending_rpc_request: Option<Vec<oneshot::Sender<Option<RpcVoteAccountStatus>>>>,
 }
 
 impl RpcRequestData {
@@ -31,7 +31,7 @@ impl RpcRequestData {
         current_slot: Slot,
         epoch: u64,
         config: GetVoteAccountsConfig,
-        return_channel: oneshot::Sender<RpcVoteAccountStatus>,
+        return_channel: oneshot::Sender<Option<RpcVoteAccountStatus>>,
         votestore: &mut VoteStore,
     ) {
         match self.pending_rpc_request {
@@ -47,7 +47,7 @@ impl RpcRequestData {
         &mut self,
         votes: VoteMap,
         vote_accounts: EpochVoteStakesCache,
-        rpc_vote_accounts: RpcVoteAccountStatus,
+        rpc_vote_accounts: Option<RpcVoteAccountStatus>,
         votestore: &mut VoteStore,
     ) {
         if let Err(err) = votestore.votes.merge((votes, vote_accounts)) {
@@ -90,6 +90,7 @@ impl RpcRequestData {
         {
             //validate that we have the epoch.
 
+            //without the epoch stakes the request is answered by the RPC.
             let jh = tokio::task::spawn_blocking({
                 move || match vote_accounts.vote_stakes_for_epoch(epoch) {
                     Some(stakes) => {
@@ -99,18 +100,11 @@ impl RpcRequestData {
                             &stakes.vote_stakes,
                             config,
                         );
-                        (votes, vote_accounts, rpc_vote_accounts)
+                        (votes, vote_accounts, Some(rpc_vote_accounts))
                     }
                     None => {
                         log::warn!("Get  vote account for epoch:{epoch}.  No data  available");
-                        (
-                            votes,
-                            vote_accounts,
-                            RpcVoteAccountStatus {
-                                current: vec![],
-                                delinquent: vec![],
-                            },
-                        )
+                        (votes, vote_accounts, None)
                     }
                 }
             });
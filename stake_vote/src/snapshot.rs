@@ -0,0 +1,138 @@
+use crate::stake::StakeMap;
+use crate::vote::EpochVoteStakes;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use solana_lite_rpc_core::structures::leaderschedule::CalculatedSchedule;
+use solana_sdk::hash::{hash, HASH_BYTES};
+
+//Snapshot file layout: magic | version (u32 le) | sha256 of the payload | bincode payload
+const SNAPSHOT_MAGIC: &[u8; 8] = b"LRPCSNAP";
+//increase when the snapshot content changes, older files are ignored.
+const SNAPSHOT_VERSION: u32 = 1;
+const SNAPSHOT_HEADER_LEN: usize = SNAPSHOT_MAGIC.len() + 4 + HASH_BYTES;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EpochSnapshot {
+    //epoch during which the snapshot has been taken.
+    pub epoch: u64,
+    pub stakes: StakeMap,
+    //vote accounts stakes of `epoch`
+    pub epoch_vote_stakes: EpochVoteStakes,
+    //leader schedule of `epoch` and `epoch + 1`
+    pub schedule: CalculatedSchedule,
+}
+
+impl EpochSnapshot {
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        let payload = bincode::serialize(self)?;
+        let mut bytes = Vec::with_capacity(SNAPSHOT_HEADER_LEN + payload.len());
+        bytes.extend_from_slice(SNAPSHOT_MAGIC);
+        bytes.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(hash(&payload).as_ref());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.len() < SNAPSHOT_HEADER_LEN || !bytes.starts_with(SNAPSHOT_MAGIC) {
+            bail!("Not a stake and vote snapshot");
+        }
+        let (version, rest) = bytes[SNAPSHOT_MAGIC.len()..].split_at(4);
+        let version = u32::from_le_bytes(version.try_into()?);
+        if version != SNAPSHOT_VERSION {
+            bail!("Unsupported snapshot version:{version}, expected:{SNAPSHOT_VERSION}");
+        }
+        let (checksum, payload) = rest.split_at(HASH_BYTES);
+        if hash(payload).as_ref() != checksum {
+            bail!("Snapshot checksum mismatch, the file is corrupted");
+        }
+        Ok(bincode::deserialize(payload)?)
+    }
+
+    //write a temporary file renamed at the end to never leave a partially written snapshot.
+    pub fn save(&self, file_path: &str) -> anyhow::Result<()> {
+        let tmp_file_path = format!("{file_path}.tmp");
+        std::fs::write(&tmp_file_path, self.to_bytes()?)?;
+        std::fs::rename(&tmp_file_path, file_path)?;
+        Ok(())
+    }
+
+    //load the snapshot only if it has been taken during `current_epoch`.
+    pub fn load(file_path: &str, current_epoch: u64) -> anyhow::Result<Self> {
+        let bytes = std::fs::read(file_path)
+            .with_context(|| format!("Can't read snapshot file:{file_path}"))?;
+        let snapshot = Self::from_bytes(&bytes)?;
+        if snapshot.epoch != current_epoch {
+            bail!(
+                "Snapshot of epoch:{} doesn't correspond to the current epoch:{current_epoch}",
+                snapshot.epoch
+            );
+        }
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stake::StoredStake;
+    use crate::vote::StoredVote;
+    use solana_lite_rpc_core::structures::leaderschedule::LeaderScheduleData;
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn test_snapshot() -> EpochSnapshot {
+        let (stake_pubkey, vote_pubkey) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let stake = StoredStake {
+            pubkey: stake_pubkey,
+            lamports: 1_000,
+            ..Default::default()
+        };
+        let vote = StoredVote {
+            pubkey: vote_pubkey,
+            ..Default::default()
+        };
+        EpochSnapshot {
+            epoch: 10,
+            stakes: HashMap::from([(stake_pubkey, stake)]),
+            epoch_vote_stakes: EpochVoteStakes {
+                vote_stakes: HashMap::from([(vote_pubkey, (1_000, Arc::new(vote)))]),
+                epoch: 10,
+            },
+            schedule: CalculatedSchedule {
+                current: Some(LeaderScheduleData {
+                    schedule_by_node: HashMap::from([(vote_pubkey.to_string(), vec![0, 1])]),
+                    schedule_by_slot: vec![vote_pubkey, vote_pubkey],
+                    epoch: 10,
+                }),
+                next: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let bytes = test_snapshot().to_bytes().unwrap();
+        let snapshot = EpochSnapshot::from_bytes(&bytes).unwrap();
+
+        assert_eq!(snapshot.epoch, 10);
+        assert_eq!(snapshot.stakes.len(), 1);
+        assert_eq!(snapshot.epoch_vote_stakes.vote_stakes.len(), 1);
+        assert_eq!(snapshot.schedule.current.unwrap().schedule_by_slot.len(), 2);
+    }
+
+    #[test]
+    fn test_snapshot_corrupted() {
+        let mut bytes = test_snapshot().to_bytes().unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        assert!(EpochSnapshot::from_bytes(&bytes).is_err());
+
+        let mut bytes = test_snapshot().to_bytes().unwrap();
+        bytes[SNAPSHOT_MAGIC.len()] += 1;
+        assert!(EpochSnapshot::from_bytes(&bytes).is_err());
+
+        assert!(EpochSnapshot::from_bytes(b"{}").is_err());
+    }
+}
@@ -147,3 +147,10 @@ pub fn merge_program_account_in_strake_map(
             StakeStore::notify_stake(stake_map, stake);
         });
 }
+
+//merge the stakes of a snapshot, newer stakes already notified by geyser are kept.
+pub fn merge_snapshot_in_stake_map(stake_map: &mut StakeMap, snapshot_stakes: StakeMap) {
+    snapshot_stakes
+        .into_values()
+        .for_each(|stake| StakeStore::notify_stake(stake_map, stake));
+}
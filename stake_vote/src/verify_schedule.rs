@@ -0,0 +1,121 @@
+use crate::Slot;
+use prometheus::core::GenericGauge;
+use prometheus::{opts, register_int_counter, register_int_gauge, IntCounter};
+use solana_lite_rpc_core::structures::leaderschedule::LeaderScheduleData;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+
+lazy_static::lazy_static! {
+    static ref LEADER_SCHEDULE_MISMATCH_SLOTS: GenericGauge<prometheus::core::AtomicI64> =
+    register_int_gauge!(opts!("literpc_leader_schedule_mismatch_slots", "Number of slots with a different leader in the calculated and rpc leader schedules")).unwrap();
+
+    static ref LEADER_SCHEDULE_VERIFIED_EPOCH: GenericGauge<prometheus::core::AtomicI64> =
+    register_int_gauge!(opts!("literpc_leader_schedule_verified_epoch", "Last epoch of the calculated leader schedule verified with the rpc")).unwrap();
+
+    static ref LEADER_SCHEDULE_REFUSED: IntCounter =
+    register_int_counter!(opts!("literpc_leader_schedule_refused", "Number of calculated leader schedules replaced by the rpc one")).unwrap();
+}
+
+// number of mismatching slots logged
+const NB_MISMATCHING_SLOTS_LOGGED: usize = 10;
+
+// slot indexes in the epoch with a different leader, missing leaders are mismatches
+pub fn mismatching_slots(calculated_leaders: &[Pubkey], rpc_leaders: &[Pubkey]) -> Vec<usize> {
+    (0..calculated_leaders.len().max(rpc_leaders.len()))
+        .filter(|index| calculated_leaders.get(*index) != rpc_leaders.get(*index))
+        .collect()
+}
+
+// Compare the leader schedule calculated from the stakes and votes with the rpc one of the same epoch.
+// Return the schedule to use: the calculated one, or the rpc one when more than
+// `max_mismatch_percent` of the slots have a different leader.
+pub async fn verify_leader_schedule(
+    rpc_client: Arc<RpcClient>,
+    first_slot_in_epoch: Slot,
+    calculated_schedule: LeaderScheduleData,
+    max_mismatch_percent: f64,
+) -> LeaderScheduleData {
+    let epoch = calculated_schedule.epoch;
+    let rpc_schedule_by_node = match rpc_client
+        .get_leader_schedule(Some(first_slot_in_epoch))
+        .await
+    {
+        Ok(Some(rpc_schedule_by_node)) => rpc_schedule_by_node,
+        Ok(None) => {
+            log::warn!(
+                "Rpc has no leader schedule for epoch:{epoch}, calculated schedule not verified"
+            );
+            return calculated_schedule;
+        }
+        Err(err) => {
+            log::warn!("Can't get rpc leader schedule for epoch:{epoch}, calculated schedule not verified:{err}");
+            return calculated_schedule;
+        }
+    };
+    let rpc_schedule_by_slot = match crate::leader_schedule::calculate_slot_leaders_from_schedule(
+        &rpc_schedule_by_node,
+    ) {
+        Ok(rpc_schedule_by_slot) => rpc_schedule_by_slot,
+        Err(err) => {
+            log::warn!("Rpc leader schedule for epoch:{epoch} is invalid, calculated schedule not verified:{err}");
+            return calculated_schedule;
+        }
+    };
+
+    let mismatches =
+        mismatching_slots(&calculated_schedule.schedule_by_slot, &rpc_schedule_by_slot);
+    LEADER_SCHEDULE_MISMATCH_SLOTS.set(mismatches.len() as i64);
+    LEADER_SCHEDULE_VERIFIED_EPOCH.set(epoch as i64);
+
+    if mismatches.is_empty() {
+        log::info!("Calculated leader schedule for epoch:{epoch} verified with the rpc");
+        return calculated_schedule;
+    }
+
+    let nb_slots = calculated_schedule
+        .schedule_by_slot
+        .len()
+        .max(rpc_schedule_by_slot.len());
+    let mismatch_percent = mismatches.len() as f64 * 100.0 / nb_slots as f64;
+    log::warn!(
+        "Calculated leader schedule for epoch:{epoch} differs from the rpc on {} slots ({mismatch_percent:.2}%), first slots:{:?}",
+        mismatches.len(),
+        mismatches
+            .iter()
+            .take(NB_MISMATCHING_SLOTS_LOGGED)
+            .map(|index| first_slot_in_epoch + *index as u64)
+            .collect::<Vec<_>>()
+    );
+
+    if mismatch_percent > max_mismatch_percent {
+        log::error!(
+            "Calculated leader schedule for epoch:{epoch} refused, more than {max_mismatch_percent}% of mismatching slots, using the rpc one"
+        );
+        LEADER_SCHEDULE_REFUSED.inc();
+        LeaderScheduleData {
+            schedule_by_node: rpc_schedule_by_node,
+            schedule_by_slot: rpc_schedule_by_slot,
+            epoch,
+        }
+    } else {
+        calculated_schedule
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mismatching_slots() {
+        let (leader1, leader2) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert!(mismatching_slots(&[leader1, leader2], &[leader1, leader2]).is_empty());
+        assert_eq!(
+            mismatching_slots(&[leader1, leader2, leader2], &[leader1, leader1, leader2]),
+            vec![1]
+        );
+        assert_eq!(mismatching_slots(&[leader1], &[leader1, leader2]), vec![1]);
+    }
+}
@@ -18,7 +18,7 @@ use std::sync::Arc;
 pub type VoteMap = HashMap<Pubkey, Arc<StoredVote>>;
 pub type VoteContent = (VoteMap, EpochVoteStakesCache);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EpochVoteStakes {
     pub vote_stakes: HashMap<Pubkey, (u64, Arc<StoredVote>)>,
     pub epoch: u64,
@@ -233,6 +233,17 @@ pub fn merge_program_account_in_vote_map(
         });
 }
 
+//restore the vote accounts of a snapshot epoch stakes, newer votes already notified by geyser are kept.
+pub fn merge_epoch_vote_stakes_in_vote_map(
+    vote_map: &mut VoteMap,
+    epoch_vote_stakes: &EpochVoteStakes,
+) {
+    epoch_vote_stakes
+        .vote_stakes
+        .values()
+        .for_each(|(_, vote)| VoteStore::vote_map_insert_vote(vote_map, StoredVote::clone(vote)));
+}
+
 // Validators that are this number of slots behind are considered delinquent
 pub fn get_rpc_vote_accounts_info(
     current_slot: Slot,
@@ -251,6 +262,11 @@ pub fn get_rpc_vote_accounts_info(
         Vec<RpcVoteAccountInfo>,
     ) = votes
         .values()
+        .filter(|vote| {
+            config
+                .vote_pubkey
+                .map_or(true, |vote_pubkey| vote.pubkey == vote_pubkey)
+        })
         .map(|vote| {
             let (stake, epoch_vote_account) = vote_accounts
                 .get(&vote.pubkey)